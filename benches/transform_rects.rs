@@ -0,0 +1,87 @@
+// Compares the batch `outer_transformed_rects` methods against transforming
+// each rectangle with `outer_transformed_rect`.
+//
+// This uses a plain `main` rather than a benchmarking framework to avoid adding
+// a dev-dependency. Run with `cargo bench --bench transform_rects`.
+
+use euclid::default::{Rect, Transform2D, Transform3D};
+use euclid::{rect, vec2, vec3, Angle};
+use std::time::{Duration, Instant};
+
+const RECTS: usize = 1000;
+const ITERATIONS: u32 = 1000;
+
+/// Keeps the optimizer from assuming anything about `value`.
+///
+/// `std::hint::black_box` would do, but it requires Rust 1.66.
+fn black_box<T>(value: T) -> T {
+    // Safety: `value` is valid for reads, and forgotten so that it isn't dropped
+    // twice.
+    unsafe {
+        let result = core::ptr::read_volatile(&value);
+        core::mem::forget(value);
+        result
+    }
+}
+
+fn measure(name: &str, mut f: impl FnMut()) {
+    // Warm up before timing.
+    for _ in 0..ITERATIONS / 10 {
+        f();
+    }
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed: Duration = start.elapsed();
+    println!("{:<24} {:>10.2?} per iteration", name, elapsed / ITERATIONS);
+}
+
+fn main() {
+    let input: Vec<Rect<f32>> = (0..RECTS)
+        .map(|i| {
+            let i = i as f32;
+            rect(
+                i * 3.0 - 500.0,
+                i * 7.0 % 400.0 - 200.0,
+                i % 50.0 + 1.0,
+                i % 30.0 + 1.0,
+            )
+        })
+        .collect();
+
+    let t2d: Transform2D<f32> = Transform2D::rotation(Angle::radians(0.5))
+        .then_scale(-1.5, 2.0)
+        .then_translate(vec2(10.0, -20.0));
+    let mut output = vec![Rect::zero(); RECTS];
+    measure("2d per-rect", || {
+        for (r, out) in input.iter().zip(output.iter_mut()) {
+            *out = black_box(&t2d).outer_transformed_rect(r);
+        }
+        black_box(&mut output);
+    });
+    measure("2d batch", || {
+        black_box(&t2d).outer_transformed_rects(&input, &mut output);
+        black_box(&mut output);
+    });
+
+    let affine: Transform3D<f32> = Transform3D::rotation(0.0, 0.0, 1.0, Angle::radians(0.5))
+        .then_translate(vec3(10.0, -20.0, 5.0));
+    // Rotating before the projection gives the rects a perspective component, so
+    // this goes through the general path.
+    let perspective: Transform3D<f32> = Transform3D::rotation(0.0, 1.0, 0.0, Angle::radians(0.2))
+        .then(&Transform3D::perspective(1000.0));
+    let mut output = vec![None; RECTS];
+    for (name, t3d) in [("3d affine", affine), ("3d perspective", perspective)] {
+        measure(&format!("{} per-rect", name), || {
+            for (r, out) in input.iter().zip(output.iter_mut()) {
+                *out = black_box(&t3d).outer_transformed_rect(r);
+            }
+            black_box(&mut output);
+        });
+        measure(&format!("{} batch", name), || {
+            black_box(&t3d).outer_transformed_rects(&input, &mut output);
+            black_box(&mut output);
+        });
+    }
+}
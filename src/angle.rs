@@ -381,3 +381,14 @@ fn sum() {
     let sum = A::radians(6.0);
     assert_eq!(angles.iter().sum::<A>(), sum);
 }
+
+#[test]
+fn degrees() {
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    assert!(Angle::degrees(180.0).approx_eq(&Angle::radians(PI)));
+    assert!(Angle::degrees(90.0f64).get().approx_eq(&FRAC_PI_2));
+    assert!(Angle::radians(PI).to_degrees().approx_eq(&180.0));
+    assert!((Angle::degrees(30.0) + Angle::degrees(60.0)).approx_eq(&Angle::frac_pi_2()));
+    assert!((Angle::degrees(90.0) * 2.0 - Angle::degrees(90.0)).approx_eq(&Angle::frac_pi_2()));
+}
@@ -61,7 +61,10 @@ use core::ops::{Add, Div, DivAssign, Mul, MulAssign, Range, Sub};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde",
-    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+    serde(
+        bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"),
+        deny_unknown_fields
+    )
 )]
 pub struct Box2D<T, U> {
     pub min: Point2D<T, U>,
@@ -75,6 +78,8 @@ impl<T: Hash, U> Hash for Box2D<T, U> {
     }
 }
 
+approx_impls!(Box2D<U> { min, max });
+
 impl<T: Copy, U> Copy for Box2D<T, U> {}
 
 impl<T: Clone, U> Clone for Box2D<T, U> {
@@ -149,6 +154,19 @@ impl<T, U> Box2D<T, U> {
             max: point2(size.width, size.height),
         }
     }
+
+    /// Creates a `Box2D` of the given size, centered on the origin.
+    ///
+    /// With integer coordinates and an odd size, the extra unit is on the positive side.
+    #[inline]
+    pub fn from_size_centered(size: Size2D<T, U>) -> Self
+    where
+        T: Copy + Zero + One + Add<T, Output = T> + Sub<T, Output = T> + Div<T, Output = T>,
+    {
+        let two = T::one() + T::one();
+        let min = point2(T::zero() - size.width / two, T::zero() - size.height / two);
+        Box2D::from_origin_and_size(min, size)
+    }
 }
 
 impl<T, U> Box2D<T, U>
@@ -263,6 +281,19 @@ where
         Some(*self)
     }
 
+    /// Returns the point within the box that is closest to `p`.
+    ///
+    /// Each coordinate of `p` is clamped into the corresponding range of the box.
+    /// If the box is empty, `p` is returned unchanged.
+    #[inline]
+    pub fn clamp_point(&self, p: Point2D<T, U>) -> Point2D<T, U> {
+        if self.is_empty() {
+            return p;
+        }
+
+        p.clamp(self.min, self.max)
+    }
+
     /// Computes the intersection of two boxes, returning `None` if the boxes do not intersect.
     #[inline]
     pub fn intersection(&self, other: &Self) -> Option<Self> {
@@ -306,6 +337,34 @@ where
             max: point2(max(self.max.x, other.max.x), max(self.max.y, other.max.y)),
         }
     }
+
+    /// Splits this box with a vertical line at `x`, returning the parts on the left
+    /// and on the right of the line.
+    ///
+    /// A part is `None` if it would be empty, so a line outside of the box yields
+    /// the whole box on one side and `None` on the other.
+    #[inline]
+    pub fn split_at_x(&self, x: T) -> (Option<Self>, Option<Self>) {
+        let x = max(self.min.x, min(x, self.max.x));
+        let left = Box2D::new(self.min, point2(x, self.max.y));
+        let right = Box2D::new(point2(x, self.min.y), self.max);
+
+        (left.to_non_empty(), right.to_non_empty())
+    }
+
+    /// Splits this box with a horizontal line at `y`, returning the parts above
+    /// and below the line.
+    ///
+    /// A part is `None` if it would be empty, so a line outside of the box yields
+    /// the whole box on one side and `None` on the other.
+    #[inline]
+    pub fn split_at_y(&self, y: T) -> (Option<Self>, Option<Self>) {
+        let y = max(self.min.y, min(y, self.max.y));
+        let top = Box2D::new(self.min, point2(self.max.x, y));
+        let bottom = Box2D::new(point2(self.min.x, y), self.max);
+
+        (top.to_non_empty(), bottom.to_non_empty())
+    }
 }
 
 impl<T, U> Box2D<T, U>
@@ -349,6 +408,10 @@ where
         self.max.y - self.min.y
     }
 
+    /// Converts this box into a rectangle with the same unit.
+    ///
+    /// A negative box produces a rectangle with a negative size, see
+    /// [`to_rect_lossy`](Self::to_rect_lossy).
     #[inline]
     pub fn to_rect(&self) -> Rect<T, U> {
         Rect {
@@ -356,6 +419,21 @@ where
             size: self.size(),
         }
     }
+
+    /// Converts this box into a rectangle with the same unit, clamping negative
+    /// sizes to zero.
+    ///
+    /// The rectangle's origin is always `self.min`.
+    #[inline]
+    pub fn to_rect_lossy(&self) -> Rect<T, U>
+    where
+        T: Zero + PartialOrd,
+    {
+        Rect {
+            origin: self.min,
+            size: self.size().clamp_to_positive(),
+        }
+    }
 }
 
 impl<T, U> Box2D<T, U>
@@ -363,6 +441,11 @@ where
     T: Copy + Add<T, Output = T> + Sub<T, Output = T>,
 {
     /// Inflates the box by the specified sizes on each side respectively.
+    ///
+    /// Inflating a non-empty box by non-negative amounts always produces a
+    /// non-empty box, so the result of [`to_non_empty`] is preserved.
+    ///
+    /// [`to_non_empty`]: Self::to_non_empty
     #[inline]
     #[must_use]
     pub fn inflate(&self, width: T, height: T) -> Self {
@@ -769,6 +852,38 @@ where
     }
 }
 
+impl<T, U> Box2D<T, U>
+where
+    T: Floor + Ceil + Round + NumCast,
+{
+    /// Round with [`round`] and cast into an `i32` box.
+    ///
+    /// [`round`]: Self::round
+    #[inline]
+    pub fn to_i32_round(&self) -> Box2D<i32, U> {
+        self.round().to_i32()
+    }
+
+    /// Round with [`round_in`] and cast into an `i32` box.
+    ///
+    /// [`round_in`]: Self::round_in
+    #[inline]
+    pub fn to_i32_round_in(&self) -> Box2D<i32, U> {
+        self.round_in().to_i32()
+    }
+
+    /// Round with [`round_out`] and cast into an `i32` box.
+    ///
+    /// This is usually the right conversion from a floating point box to
+    /// integer device pixels, as it doesn't clip any part of the original box.
+    ///
+    /// [`round_out`]: Self::round_out
+    #[inline]
+    pub fn to_i32_round_out(&self) -> Box2D<i32, U> {
+        self.round_out().to_i32()
+    }
+}
+
 impl<T, U> From<Size2D<T, U>> for Box2D<T, U>
 where
     T: Copy + Zero + PartialOrd,
@@ -827,6 +942,16 @@ mod tests {
         assert_eq!(b.max, point2(100.0, 160.0));
     }
 
+    #[test]
+    fn test_from_points_iter() {
+        let b = Box2D::from_points([point2(3.0, -1.0), point2(-2.0, 4.0), point2(1.0, 7.0)]);
+        assert_eq!(b.min, point2(-2.0, -1.0));
+        assert_eq!(b.max, point2(3.0, 7.0));
+
+        let b = Box2D::from_points(core::iter::empty::<Point2D<f32, _>>());
+        assert_eq!(b, Box2D::zero());
+    }
+
     #[test]
     fn test_round_in() {
         let b = Box2D::from_points(&[point2(-25.5, -40.4), point2(60.3, 36.5)]).round_in();
@@ -854,6 +979,18 @@ mod tests {
         assert_eq!(b.max.y, 37.0);
     }
 
+    #[test]
+    fn test_to_i32_round() {
+        let b = Box2D::new(point2(-25.5, -40.4), point2(60.3, 36.5));
+        assert_eq!(b.to_i32_round(), b.round().to_i32());
+        assert_eq!(b.to_i32_round_in(), b.round_in().to_i32());
+        assert_eq!(b.to_i32_round_out(), b.round_out().to_i32());
+        assert_eq!(
+            b.to_i32_round_out(),
+            Box2D::new(point2(-26, -41), point2(61, 37))
+        );
+    }
+
     #[test]
     fn test_from_size() {
         let b = Box2D::from_size(size2(30.0, 40.0));
@@ -980,6 +1117,68 @@ mod tests {
         assert_eq!(b.center(), Point2D::zero());
     }
 
+    #[test]
+    fn test_inflate_non_empty() {
+        let b = Box2D::new(point2(0.0, 0.0), point2(1.0, 0.5));
+        let inflated = b.to_non_empty().unwrap().inflate(0.0, 1.0);
+        assert_eq!(
+            inflated.to_non_empty(),
+            Some(Box2D::new(point2(0.0, -1.0), point2(1.0, 1.5)))
+        );
+        assert!(b.inflate(3.0, 0.0).to_non_empty().is_some());
+    }
+
+    #[test]
+    fn test_from_size_centered() {
+        let b = Box2D::from_size_centered(size2(4.0, 6.0));
+        assert_eq!(b.size(), size2(4.0, 6.0));
+        assert_eq!(b.center(), Point2D::zero());
+
+        let b = Box2D::<i32>::from_size_centered(size2(3, 4));
+        assert_eq!(b.size(), size2(3, 4));
+        assert_eq!(b, Box2D::new(point2(-1, -2), point2(2, 2)));
+    }
+
+    #[test]
+    fn test_to_rect() {
+        let b = Box2D::new(point2(1.0, 2.0), point2(4.0, 6.0));
+        assert_eq!(b.to_rect().to_box2d(), b);
+        assert_eq!(b.to_rect_lossy(), b.to_rect());
+
+        let empty = Box2D::new(point2(4.0, 2.0), point2(1.0, 6.0));
+        assert_eq!(empty.to_rect().size, size2(-3.0, 4.0));
+        let r = empty.to_rect_lossy();
+        assert_eq!(r.origin, point2(4.0, 2.0));
+        assert_eq!(r.size, size2(0.0, 4.0));
+        assert!(r.is_empty());
+    }
+
+    #[test]
+    fn test_clamp_point() {
+        let b = Box2D::new(point2(0.0, 0.0), point2(10.0, 20.0));
+        assert_eq!(b.clamp_point(point2(5.0, 5.0)), point2(5.0, 5.0));
+        assert_eq!(b.clamp_point(point2(-5.0, 5.0)), point2(0.0, 5.0));
+        assert_eq!(b.clamp_point(point2(15.0, 25.0)), point2(10.0, 20.0));
+
+        let empty = Box2D::new(point2(10.0, 0.0), point2(0.0, 20.0));
+        assert_eq!(empty.clamp_point(point2(15.0, 25.0)), point2(15.0, 25.0));
+    }
+
+    #[test]
+    fn test_split() {
+        let b = Box2D::new(point2(0.0, 0.0), point2(10.0, 20.0));
+        assert_eq!(
+            b.split_at_x(4.0),
+            (
+                Some(Box2D::new(point2(0.0, 0.0), point2(4.0, 20.0))),
+                Some(Box2D::new(point2(4.0, 0.0), point2(10.0, 20.0)))
+            )
+        );
+        assert_eq!(b.split_at_y(20.0), (Some(b), None));
+        assert_eq!(b.split_at_x(-1.0), (None, Some(b)));
+        assert_eq!(Box2D::zero().split_at_y(0.0), (None, None));
+    }
+
     #[test]
     fn test_is_empty() {
         for i in 0..2 {
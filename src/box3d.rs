@@ -8,6 +8,7 @@
 // except according to those terms.
 
 use super::UnknownUnit;
+use crate::approxeq::ApproxEq;
 use crate::approxord::{max, min};
 use crate::num::*;
 use crate::point::{point3, Point3D};
@@ -32,7 +33,10 @@ use core::ops::{Add, Div, DivAssign, Mul, MulAssign, Range, Sub};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde",
-    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+    serde(
+        bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"),
+        deny_unknown_fields
+    )
 )]
 pub struct Box3D<T, U> {
     pub min: Point3D<T, U>,
@@ -46,6 +50,8 @@ impl<T: Hash, U> Hash for Box3D<T, U> {
     }
 }
 
+approx_impls!(Box3D<U> { min, max });
+
 impl<T: Copy, U> Copy for Box3D<T, U> {}
 
 impl<T: Clone, U> Clone for Box3D<T, U> {
@@ -305,6 +311,53 @@ where
             ),
         )
     }
+
+    /// Returns the point within the box that is closest to `p`.
+    ///
+    /// Each coordinate of `p` is clamped into the corresponding range of the box.
+    /// If the box is empty, `p` is returned unchanged.
+    #[inline]
+    pub fn clamp_point(&self, p: Point3D<T, U>) -> Point3D<T, U> {
+        if self.is_empty() {
+            return p;
+        }
+
+        p.clamp(self.min, self.max)
+    }
+}
+
+impl<T, U> Box3D<T, U>
+where
+    T: Copy + PartialOrd + Add<T, Output = T> + Sub<T, Output = T> + Mul<T, Output = T>,
+{
+    /// Returns the squared distance between `p` and the closest point within the box.
+    ///
+    /// Points inside of the box are at distance zero. Returns `None` if the box is
+    /// empty, since it has no closest point.
+    #[inline]
+    pub fn square_distance_to_point(&self, p: Point3D<T, U>) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(self.clamp_point(p).square_distance_to(p))
+    }
+
+    /// Returns the distance between `p` and the closest point within the box.
+    ///
+    /// Points inside of the box are at distance zero. Returns `None` if the box is
+    /// empty, since it has no closest point.
+    #[inline]
+    pub fn distance_to_point(&self, p: Point3D<T, U>) -> Option<T>
+    where
+        T: Float,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(self.clamp_point(p).distance_to(p))
+    }
 }
 
 impl<T, U> Box3D<T, U>
@@ -349,6 +402,19 @@ where
     pub fn depth(&self) -> T {
         self.max.z - self.min.z
     }
+
+    /// Returns `true` if the width, height or depth of the box is not greater
+    /// than `eps`.
+    ///
+    /// Like [`is_empty`](Self::is_empty), but treats boxes thinner than `eps`
+    /// along any axis as empty.
+    #[inline]
+    pub fn is_empty_eps(&self, eps: T) -> bool
+    where
+        T: PartialOrd,
+    {
+        !(self.width() > eps && self.height() > eps && self.depth() > eps)
+    }
 }
 
 impl<T, U> Box3D<T, U>
@@ -481,6 +547,40 @@ where
     }
 }
 
+impl<T, U> Box3D<T, U>
+where
+    T: Copy + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Returns this box scaled by `factor` about its center.
+    ///
+    /// The center of the returned box is the same as the center of `self`.
+    #[inline]
+    #[must_use]
+    pub fn scale_about_center(&self, factor: T) -> Self {
+        let center = self.center();
+        Box3D::new(
+            center + (self.min - center) * factor,
+            center + (self.max - center) * factor,
+        )
+    }
+}
+
+impl<T: Copy, U> Box3D<T, U> {
+    /// Calls `f` with each of the eight corners of the box.
+    ///
+    /// Corners are visited with `x` varying fastest, then `y`, then `z`,
+    /// starting at `min` and ending at `max`.
+    pub fn for_each_corner<F: FnMut(Point3D<T, U>)>(&self, mut f: F) {
+        for &z in &[self.min.z, self.max.z] {
+            for &y in &[self.min.y, self.max.y] {
+                for &x in &[self.min.x, self.max.x] {
+                    f(Point3D::new(x, y, z));
+                }
+            }
+        }
+    }
+}
+
 impl<T, U> Box3D<T, U>
 where
     T: Copy + Mul<T, Output = T> + Sub<T, Output = T>,
@@ -799,6 +899,23 @@ impl<T: Default, U> Default for Box3D<T, U> {
     }
 }
 
+impl<T: ApproxEq<T>, U> ApproxEq<T> for Box3D<T, U> {
+    #[inline]
+    fn approx_epsilon() -> T {
+        T::approx_epsilon()
+    }
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Self, eps: &T) -> bool {
+        self.min.x.approx_eq_eps(&other.min.x, eps)
+            && self.min.y.approx_eq_eps(&other.min.y, eps)
+            && self.min.z.approx_eq_eps(&other.min.z, eps)
+            && self.max.x.approx_eq_eps(&other.max.x, eps)
+            && self.max.y.approx_eq_eps(&other.max.y, eps)
+            && self.max.z.approx_eq_eps(&other.max.z, eps)
+    }
+}
+
 /// Shorthand for `Box3D::new(Point3D::new(x1, y1, z1), Point3D::new(x2, y2, z2))`.
 pub fn box3d<T: Copy, U>(
     min_x: T,
@@ -1062,6 +1179,106 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_approx_eq() {
+        use crate::approxeq::ApproxEq;
+
+        let b = Box3D::new(point3(0.0, 0.0, 0.0), point3(1.0, 2.0, 3.0));
+        let perturbed = Box3D::new(point3(1e-7, 0.0, -1e-7), point3(1.0, 2.0 + 1e-7, 3.0));
+        assert!(b.approx_eq(&perturbed));
+        assert!(!b.approx_eq(&b.translate(vec3(0.0, 0.0, 0.1))));
+        assert!(b.approx_eq_eps(&b.translate(vec3(0.0, 0.0, 0.1)), &0.2));
+    }
+
+    #[test]
+    fn test_is_empty_eps() {
+        let b = Box3D::new(point3(0.0, 0.0, 0.0), point3(1.0, 2.0, 0.001));
+        assert!(!b.is_empty());
+        assert!(b.is_empty_eps(0.01));
+        assert!(!b.is_empty_eps(0.0001));
+    }
+
+    #[test]
+    fn test_scale_about_center() {
+        let b = Box3D::new(point3(0.0, 0.0, 0.0), point3(2.0, 4.0, 6.0));
+        let scaled = b.scale_about_center(2.0);
+        assert_eq!(scaled.center(), b.center());
+        assert_eq!(scaled.size(), size3(4.0, 8.0, 12.0));
+        assert_eq!(
+            scaled,
+            Box3D::new(point3(-1.0, -2.0, -3.0), point3(3.0, 6.0, 9.0))
+        );
+    }
+
+    #[test]
+    fn test_clamp_point() {
+        let b = Box3D::new(point3(0.0, 0.0, 0.0), point3(10.0, 20.0, 30.0));
+        assert_eq!(
+            b.clamp_point(point3(-5.0, 25.0, 40.0)),
+            point3(0.0, 20.0, 30.0)
+        );
+        assert_eq!(
+            b.clamp_point(point3(15.0, -1.0, -2.0)),
+            point3(10.0, 0.0, 0.0)
+        );
+        assert_eq!(b.clamp_point(point3(5.0, 5.0, 5.0)), point3(5.0, 5.0, 5.0));
+
+        // Points are returned unchanged by empty boxes.
+        let empty = Box3D::new(point3(10.0, 0.0, 0.0), point3(0.0, 20.0, 30.0));
+        assert_eq!(
+            empty.clamp_point(point3(-5.0, 25.0, 40.0)),
+            point3(-5.0, 25.0, 40.0)
+        );
+        assert_eq!(
+            empty.clamp_point(point3(5.0, 5.0, 5.0)),
+            point3(5.0, 5.0, 5.0)
+        );
+    }
+
+    #[test]
+    fn test_distance_to_point() {
+        let b = Box3D::new(point3(0.0, 0.0, 0.0), point3(10.0, 20.0, 30.0));
+
+        // Inside.
+        assert_eq!(b.distance_to_point(point3(5.0, 5.0, 5.0)), Some(0.0));
+        // Above a face.
+        assert_eq!(b.distance_to_point(point3(5.0, 5.0, 34.0)), Some(4.0));
+        assert_eq!(
+            b.square_distance_to_point(point3(5.0, 5.0, 34.0)),
+            Some(16.0)
+        );
+        // Off a corner.
+        assert_eq!(
+            b.square_distance_to_point(point3(-1.0, 22.0, 32.0)),
+            Some(9.0)
+        );
+        assert_eq!(b.distance_to_point(point3(-1.0, 22.0, 32.0)), Some(3.0));
+
+        let empty = Box3D::new(point3(10.0, 0.0, 0.0), point3(0.0, 20.0, 30.0));
+        assert_eq!(
+            empty.square_distance_to_point(point3(-1.0, 22.0, 32.0)),
+            None
+        );
+        assert_eq!(empty.distance_to_point(point3(-1.0, 22.0, 32.0)), None);
+        assert_eq!(empty.distance_to_point(point3(5.0, 5.0, 5.0)), None);
+    }
+
+    #[test]
+    fn test_for_each_corner() {
+        let b = Box3D::new(point3(1.0, 2.0, 3.0), point3(4.0, 5.0, 6.0));
+        let mut corners = Vec::new();
+        b.for_each_corner(|p| corners.push(p));
+        assert_eq!(corners.len(), 8);
+        assert_eq!(corners[0], b.min);
+        assert_eq!(corners[1], point3(4.0, 2.0, 3.0));
+        assert_eq!(corners[2], point3(1.0, 5.0, 3.0));
+        assert_eq!(corners[4], point3(1.0, 2.0, 6.0));
+        assert_eq!(corners[7], b.max);
+        for c in &corners {
+            assert!(b.contains_inclusive(*c));
+        }
+    }
+
     #[test]
     #[rustfmt::skip]
     fn test_nan_empty_or_negative() {
@@ -213,6 +213,7 @@ impl<T: fmt::Debug, U> fmt::Debug for HomogeneousVector<T, U> {
 mod homogeneous {
     use super::HomogeneousVector;
     use crate::default::{Point2D, Point3D};
+    use crate::UnknownUnit;
 
     #[test]
     fn roundtrip() {
@@ -226,6 +227,20 @@ mod homogeneous {
         );
     }
 
+    #[test]
+    fn point3d_has_unit_w() {
+        let h = HomogeneousVector::from(Point3D::new(4.0, 5.0, 6.0));
+        assert_eq!(h, HomogeneousVector::new(4.0, 5.0, 6.0, 1.0));
+        assert_eq!(
+            HomogeneousVector::<f32, UnknownUnit>::new(8.0, 10.0, 12.0, 2.0).to_point3d(),
+            Some(Point3D::new(4.0, 5.0, 6.0))
+        );
+        assert_eq!(
+            HomogeneousVector::<f32, UnknownUnit>::new(4.0, 5.0, 6.0, 0.0).to_point3d(),
+            None
+        );
+    }
+
     #[test]
     fn negative() {
         assert_eq!(
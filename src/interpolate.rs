@@ -0,0 +1,643 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::approxeq::ApproxEq;
+use crate::{vec3, Angle, Box2D, Box3D, Length, Point2D, Point3D, Rect, RigidTransform3D};
+use crate::{
+    Rotation3D, Size2D, Size3D, Transform2D, Transform3D, UnknownUnit, Vector2D, Vector3D,
+};
+
+use num_traits::real::Real;
+use num_traits::{AsPrimitive, Float, FloatConst};
+
+/// A value that can be blended with another value of the same type.
+///
+/// This provides a common interface to the `lerp` and `slerp` methods of the
+/// geometry types, for generic code such as animation systems.
+///
+/// [`Transform2D`] and [`Transform3D`] are interpolated by decomposing them into
+/// simpler transformations, as specified for CSS transforms, and [`RigidTransform3D`]
+/// by interpolating its rotation and translation separately.
+pub trait Interpolate {
+    /// Interpolates between `self` and `other`.
+    ///
+    /// `t` is expected to be between zero and one, returning `self` for zero and
+    /// `other` for one.
+    #[must_use]
+    fn interpolate(&self, other: &Self, t: f64) -> Self;
+}
+
+macro_rules! interpolate_with_lerp {
+    ($($name:ident),*) => {
+        $(
+            /// Same as the type's `lerp` method.
+            impl<T: Real + 'static, U> Interpolate for $name<T, U>
+            where
+                f64: AsPrimitive<T>,
+            {
+                #[inline]
+                fn interpolate(&self, other: &Self, t: f64) -> Self {
+                    self.lerp(*other, t.as_())
+                }
+            }
+        )*
+    };
+}
+
+interpolate_with_lerp!(
+    Length, Point2D, Point3D, Vector2D, Vector3D, Size2D, Size3D, Rect, Box2D, Box3D
+);
+
+/// Same as [`Angle::lerp`], interpolating along the shortest path.
+impl<T: Float + FloatConst + 'static> Interpolate for Angle<T>
+where
+    f64: AsPrimitive<T>,
+{
+    #[inline]
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        self.lerp(*other, t.as_())
+    }
+}
+
+/// Same as [`Rotation3D::slerp`].
+impl<T: Real + ApproxEq<T> + 'static, Src, Dst> Interpolate for Rotation3D<T, Src, Dst>
+where
+    f64: AsPrimitive<T>,
+{
+    #[inline]
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        self.slerp(other, t.as_())
+    }
+}
+
+/// Interpolates the rotations with [`Rotation3D::slerp`] and the translations
+/// with [`Vector3D::lerp`].
+impl<T: Real + ApproxEq<T> + 'static, Src, Dst> Interpolate for RigidTransform3D<T, Src, Dst>
+where
+    f64: AsPrimitive<T>,
+{
+    #[inline]
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        RigidTransform3D::new(
+            self.rotation.slerp(&other.rotation, t.as_()),
+            self.translation.lerp(other.translation, t.as_()),
+        )
+    }
+}
+
+/// A 2d transform decomposed into a scale, followed by a rotation, a remaining
+/// linear part and a translation.
+///
+/// See <https://drafts.csswg.org/css-transforms/#decomposing-a-2d-matrix>.
+struct Decomposed2D<T> {
+    translation: (T, T),
+    scale: (T, T),
+    angle: T,
+    m11: T,
+    m12: T,
+    m21: T,
+    m22: T,
+}
+
+impl<T: Float + FloatConst> Decomposed2D<T> {
+    fn new<Src, Dst>(m: &Transform2D<T, Src, Dst>) -> Self {
+        let zero = T::zero();
+        let (mut row0x, mut row0y) = (m.m11, m.m12);
+        let (mut row1x, mut row1y) = (m.m21, m.m22);
+
+        let mut scale = (row0x.hypot(row0y), row1x.hypot(row1y));
+
+        // If the determinant is negative, one axis was flipped.
+        if row0x * row1y - row0y * row1x < zero {
+            if row0x < row1y {
+                scale.0 = -scale.0;
+            } else {
+                scale.1 = -scale.1;
+            }
+        }
+
+        // Renormalize the rows.
+        if scale.0 != zero {
+            row0x = row0x / scale.0;
+            row0y = row0y / scale.0;
+        }
+        if scale.1 != zero {
+            row1x = row1x / scale.1;
+            row1y = row1y / scale.1;
+        }
+
+        // Remove the rotation from the rows.
+        let angle = row0y.atan2(row0x);
+        let (sn, cs) = (-row0y, row0x);
+
+        Decomposed2D {
+            translation: (m.m31, m.m32),
+            scale,
+            angle,
+            m11: cs * row0x + sn * row1x,
+            m12: cs * row0y + sn * row1y,
+            m21: -sn * row0x + cs * row1x,
+            m22: -sn * row0y + cs * row1y,
+        }
+    }
+
+    /// Interpolates between two decompositions, adjusting them so that the
+    /// rotation goes the short way around.
+    fn interpolate(mut self, mut other: Self, t: T) -> Self {
+        let zero = T::zero();
+        let pi = T::PI();
+        let two_pi = pi + pi;
+
+        // If the x axis of one transform is flipped and the y axis of the other one is,
+        // convert the flip into an extra rotation.
+        if (self.scale.0 < zero && other.scale.1 < zero)
+            || (self.scale.1 < zero && other.scale.0 < zero)
+        {
+            self.scale = (-self.scale.0, -self.scale.1);
+            self.angle = if self.angle < zero {
+                self.angle + pi
+            } else {
+                self.angle - pi
+            };
+        }
+
+        if self.angle == zero {
+            self.angle = two_pi;
+        }
+        if other.angle == zero {
+            other.angle = two_pi;
+        }
+        if (self.angle - other.angle).abs() > pi {
+            if self.angle > other.angle {
+                self.angle = self.angle - two_pi;
+            } else {
+                other.angle = other.angle - two_pi;
+            }
+        }
+
+        let lerp = |a: T, b: T| a + (b - a) * t;
+        Decomposed2D {
+            translation: (
+                lerp(self.translation.0, other.translation.0),
+                lerp(self.translation.1, other.translation.1),
+            ),
+            scale: (
+                lerp(self.scale.0, other.scale.0),
+                lerp(self.scale.1, other.scale.1),
+            ),
+            angle: lerp(self.angle, other.angle),
+            m11: lerp(self.m11, other.m11),
+            m12: lerp(self.m12, other.m12),
+            m21: lerp(self.m21, other.m21),
+            m22: lerp(self.m22, other.m22),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn to_transform<Src, Dst>(&self) -> Transform2D<T, Src, Dst> {
+        let (sin, cos) = self.angle.sin_cos();
+        let (sx, sy) = self.scale;
+
+        // The rotation is applied to the remaining linear part, and the scale to
+        // the result.
+        let r11 = cos * self.m11 + sin * self.m21;
+        let r12 = cos * self.m12 + sin * self.m22;
+        let r21 = -sin * self.m11 + cos * self.m21;
+        let r22 = -sin * self.m12 + cos * self.m22;
+
+        Transform2D::new(
+            sx * r11, sx * r12,
+            sy * r21, sy * r22,
+            self.translation.0, self.translation.1,
+        )
+    }
+}
+
+/// Decomposes both transforms into a translation, a rotation, a scale and a
+/// remaining linear part, interpolates these separately and recomposes the result.
+///
+/// This is the interpolation of CSS transforms, see
+/// <https://drafts.csswg.org/css-transforms/#interpolation-of-2d-matrices>.
+impl<T: Float + FloatConst + 'static, Src, Dst> Interpolate for Transform2D<T, Src, Dst>
+where
+    f64: AsPrimitive<T>,
+{
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        Decomposed2D::new(self)
+            .interpolate(Decomposed2D::new(other), t.as_())
+            .to_transform()
+    }
+}
+
+/// A 3d transform decomposed into a scale, followed by a skew, a rotation, a
+/// translation and a perspective part.
+///
+/// See <https://drafts.csswg.org/css-transforms-2/#decomposing-a-3d-matrix>.
+struct Decomposed3D<T> {
+    scale: Vector3D<T, UnknownUnit>,
+    /// The xy, xz and yz shear factors.
+    skew: (T, T, T),
+    rotation: Rotation3D<T, UnknownUnit, UnknownUnit>,
+    translation: Vector3D<T, UnknownUnit>,
+    perspective: (T, T, T, T),
+}
+
+impl<T: Real + ApproxEq<T>> Decomposed3D<T> {
+    /// Returns `None` if the transform can't be decomposed, which is the case when
+    /// `m44` is zero or when the transform without its perspective part is singular.
+    fn new<Src, Dst>(m: &Transform3D<T, Src, Dst>) -> Option<Self> {
+        let zero = T::zero();
+        let one = T::one();
+
+        if m.m44 == zero {
+            return None;
+        }
+
+        // Normalize the matrix.
+        let mut a = m.to_arrays();
+        for row in a.iter_mut() {
+            for value in row.iter_mut() {
+                *value = *value / m.m44;
+            }
+        }
+
+        // The matrix is the product of an affine part and a perspective part which
+        // only affects the last column, so the perspective part is found by solving
+        // `affine * perspective = last column`.
+        let mut affine = a;
+        affine[0][3] = zero;
+        affine[1][3] = zero;
+        affine[2][3] = zero;
+        affine[3][3] = one;
+        let affine = Transform3D::<T, UnknownUnit, UnknownUnit>::from_arrays(affine);
+        if affine.determinant() == zero {
+            return None;
+        }
+
+        let perspective = if a[0][3] != zero || a[1][3] != zero || a[2][3] != zero {
+            let inverse = affine.inverse()?.to_arrays();
+            let rhs = [a[0][3], a[1][3], a[2][3], a[3][3]];
+            let solve = |i: usize| (0..4).fold(zero, |sum, k| sum + inverse[i][k] * rhs[k]);
+            (solve(0), solve(1), solve(2), solve(3))
+        } else {
+            (zero, zero, zero, one)
+        };
+
+        let translation = vec3(a[3][0], a[3][1], a[3][2]);
+
+        // Orthonormalize the rows of the upper 3x3 part, the scale and skew being
+        // what is removed in the process.
+        let row0: Vector3D<T, UnknownUnit> = vec3(a[0][0], a[0][1], a[0][2]);
+        let row1: Vector3D<T, UnknownUnit> = vec3(a[1][0], a[1][1], a[1][2]);
+        let row2: Vector3D<T, UnknownUnit> = vec3(a[2][0], a[2][1], a[2][2]);
+
+        let sx = row0.length();
+        let row0 = row0 / sx;
+
+        let xy = row0.dot(row1);
+        let row1 = row1 - row0 * xy;
+        let sy = row1.length();
+        let row1 = row1 / sy;
+
+        let xz = row0.dot(row2);
+        let row2 = row2 - row0 * xz;
+        let yz = row1.dot(row2);
+        let row2 = row2 - row1 * yz;
+        let sz = row2.length();
+        let (mut row0, mut row1, mut row2) = (row0, row1, row2 / sz);
+        let (xy, xz, yz) = (xy / sy, xz / sz, yz / sz);
+
+        let mut scale = vec3(sx, sy, sz);
+
+        // If the determinant is negative, the coordinate system was flipped.
+        if row0.dot(row1.cross(row2)) < zero {
+            scale = -scale;
+            row0 = -row0;
+            row1 = -row1;
+            row2 = -row2;
+        }
+
+        Some(Decomposed3D {
+            scale,
+            skew: (xy, xz, yz),
+            rotation: rotation_from_rows(row0, row1, row2),
+            translation,
+            perspective,
+        })
+    }
+
+    fn interpolate(&self, other: &Self, t: T) -> Self {
+        let zero = T::zero();
+        let lerp = |a: T, b: T| a + (b - a) * t;
+
+        // Both quaternions represent the same rotation, pick the one closest to
+        // `self.rotation`.
+        let (r1, mut r2) = (self.rotation, other.rotation);
+        if r1.i * r2.i + r1.j * r2.j + r1.k * r2.k + r1.r * r2.r < zero {
+            r2 = Rotation3D::quaternion(-r2.i, -r2.j, -r2.k, -r2.r);
+        }
+
+        let (p1, p2) = (self.perspective, other.perspective);
+        Decomposed3D {
+            scale: self.scale.lerp(other.scale, t),
+            skew: (
+                lerp(self.skew.0, other.skew.0),
+                lerp(self.skew.1, other.skew.1),
+                lerp(self.skew.2, other.skew.2),
+            ),
+            rotation: r1.slerp(&r2, t),
+            translation: self.translation.lerp(other.translation, t),
+            perspective: (
+                lerp(p1.0, p2.0),
+                lerp(p1.1, p2.1),
+                lerp(p1.2, p2.2),
+                lerp(p1.3, p2.3),
+            ),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn to_transform<Src, Dst>(&self) -> Transform3D<T, Src, Dst> {
+        let r = self.rotation.to_transform();
+        let r0: Vector3D<T, UnknownUnit> = vec3(r.m11, r.m12, r.m13);
+        let r1: Vector3D<T, UnknownUnit> = vec3(r.m21, r.m22, r.m23);
+        let r2: Vector3D<T, UnknownUnit> = vec3(r.m31, r.m32, r.m33);
+
+        let (xy, xz, yz) = self.skew;
+        let row0 = r0 * self.scale.x;
+        let row1 = (r1 + r0 * xy) * self.scale.y;
+        let row2 = (r2 + r0 * xz + r1 * yz) * self.scale.z;
+        let t = self.translation;
+
+        let (px, py, pz, pw) = self.perspective;
+        let p = vec3(px, py, pz);
+
+        Transform3D::new(
+            row0.x, row0.y, row0.z, row0.dot(p),
+            row1.x, row1.y, row1.z, row1.dot(p),
+            row2.x, row2.y, row2.z, row2.dot(p),
+            t.x, t.y, t.z, t.dot(p) + pw,
+        )
+    }
+}
+
+/// Returns the rotation whose matrix has the given orthonormal rows.
+///
+/// The quaternion is computed from its largest component to avoid dividing by a
+/// value close to zero.
+fn rotation_from_rows<T: Real>(
+    row0: Vector3D<T, UnknownUnit>,
+    row1: Vector3D<T, UnknownUnit>,
+    row2: Vector3D<T, UnknownUnit>,
+) -> Rotation3D<T, UnknownUnit, UnknownUnit> {
+    let zero = T::zero();
+    let one = T::one();
+    let two = one + one;
+    let quarter = one / (two + two);
+
+    let (m11, m12, m13) = (row0.x, row0.y, row0.z);
+    let (m21, m22, m23) = (row1.x, row1.y, row1.z);
+    let (m31, m32, m33) = (row2.x, row2.y, row2.z);
+
+    let trace = m11 + m22 + m33;
+    let (i, j, k, r) = if trace > zero {
+        let s = (one + trace).sqrt() * two;
+        (
+            (m23 - m32) / s,
+            (m31 - m13) / s,
+            (m12 - m21) / s,
+            s * quarter,
+        )
+    } else if m11 > m22 && m11 > m33 {
+        let s = (one + m11 - m22 - m33).sqrt() * two;
+        (
+            s * quarter,
+            (m12 + m21) / s,
+            (m31 + m13) / s,
+            (m23 - m32) / s,
+        )
+    } else if m22 > m33 {
+        let s = (one + m22 - m11 - m33).sqrt() * two;
+        (
+            (m12 + m21) / s,
+            s * quarter,
+            (m23 + m32) / s,
+            (m31 - m13) / s,
+        )
+    } else {
+        let s = (one + m33 - m11 - m22).sqrt() * two;
+        (
+            (m31 + m13) / s,
+            (m23 + m32) / s,
+            s * quarter,
+            (m12 - m21) / s,
+        )
+    };
+
+    Rotation3D::unit_quaternion(i, j, k, r)
+}
+
+/// Decomposes both transforms into a scale, a skew, a rotation, a translation and
+/// a perspective part, interpolates these separately and recomposes the result.
+/// The rotations are interpolated with [`Rotation3D::slerp`].
+///
+/// This is the interpolation of CSS transforms, see
+/// <https://drafts.csswg.org/css-transforms-2/#interpolation-of-3d-matrices>.
+/// As in CSS, if either transform can't be decomposed, this returns `self` if `t`
+/// is less than one half and `other` otherwise.
+impl<T: Real + ApproxEq<T> + 'static, Src, Dst> Interpolate for Transform3D<T, Src, Dst>
+where
+    f64: AsPrimitive<T>,
+{
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        match (Decomposed3D::new(self), Decomposed3D::new(other)) {
+            (Some(a), Some(b)) => a.interpolate(&b, t.as_()).to_transform(),
+            _ if t < 0.5 => *self,
+            _ => *other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Interpolate;
+    use crate::approxeq::ApproxEq;
+    use crate::default::{Box2D, Box3D, Length, Rect, RigidTransform3D, Rotation3D};
+    use crate::default::{Transform2D, Transform3D};
+    use crate::{point2, point3, rect, size2, size3, vec2, vec3, Angle};
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    fn check<T: Interpolate + PartialEq + core::fmt::Debug>(a: T, b: T, lerp: impl Fn(f64) -> T) {
+        for &t in &[0.0, 0.25, 0.5, 1.0] {
+            assert_eq!(a.interpolate(&b, t), lerp(t));
+        }
+    }
+
+    #[test]
+    fn test_lerp() {
+        let (l1, l2): (Length<f64>, Length<f64>) = (Length::new(1.0), Length::new(3.0));
+        check(l1, l2, |t| l1.lerp(l2, t));
+
+        let (p1, p2) = (point2::<f64, ()>(1.0, 2.0), point2(5.0, -2.0));
+        check(p1, p2, |t| p1.lerp(p2, t));
+
+        let (p1, p2) = (point3::<f32, ()>(1.0, 2.0, 3.0), point3(5.0, -2.0, 0.0));
+        check(p1, p2, |t| p1.lerp(p2, t as f32));
+
+        let (v1, v2) = (vec2::<f64, ()>(1.0, 2.0), vec2(5.0, -2.0));
+        check(v1, v2, |t| v1.lerp(v2, t));
+
+        let (v1, v2) = (vec3::<f64, ()>(1.0, 2.0, 3.0), vec3(5.0, -2.0, 0.0));
+        check(v1, v2, |t| v1.lerp(v2, t));
+
+        let (s1, s2) = (size2::<f64, ()>(1.0, 2.0), size2(5.0, 4.0));
+        check(s1, s2, |t| s1.lerp(s2, t));
+
+        let (s1, s2) = (size3::<f64, ()>(1.0, 2.0, 3.0), size3(5.0, 4.0, 0.0));
+        check(s1, s2, |t| s1.lerp(s2, t));
+
+        let (r1, r2): (Rect<f64>, Rect<f64>) = (rect(0.0, 0.0, 1.0, 1.0), rect(2.0, 4.0, 3.0, 5.0));
+        check(r1, r2, |t| r1.lerp(r2, t));
+
+        let (b1, b2) = (
+            Box2D::new(point2(0.0, 0.0), point2(1.0, 1.0)),
+            Box2D::new(point2(2.0, 4.0), point2(3.0, 5.0)),
+        );
+        check(b1, b2, |t| b1.lerp(b2, t));
+
+        let (b1, b2) = (
+            Box3D::new(point3(0.0, 0.0, 0.0), point3(1.0, 1.0, 1.0)),
+            Box3D::new(point3(2.0, 4.0, 6.0), point3(3.0, 5.0, 7.0)),
+        );
+        check(b1, b2, |t| b1.lerp(b2, t));
+    }
+
+    #[test]
+    fn test_angle() {
+        let a1 = Angle::radians(0.1);
+        let a2 = Angle::radians(2.0 * PI - 0.1);
+        check(a1, a2, |t| a1.lerp(a2, t));
+        assert!(a1.interpolate(&a2, 0.5).approx_eq(&Angle::zero()));
+    }
+
+    #[test]
+    fn test_rotation() {
+        let r1: Rotation3D<f64> = Rotation3D::identity();
+        let r2 = Rotation3D::around_z(Angle::radians(FRAC_PI_2));
+        check(r1, r2, |t| r1.slerp(&r2, t));
+    }
+
+    #[test]
+    fn test_rigid_transform() {
+        let r1 = RigidTransform3D::new(Rotation3D::identity(), vec3(1.0, 2.0, 3.0));
+        let r2 = RigidTransform3D::new(
+            Rotation3D::around_z(Angle::radians(FRAC_PI_2)),
+            vec3(3.0, 0.0, -1.0),
+        );
+        check(r1, r2, |t| {
+            RigidTransform3D::new(
+                r1.rotation.slerp(&r2.rotation, t),
+                r1.translation.lerp(r2.translation, t),
+            )
+        });
+    }
+
+    #[test]
+    fn test_transform_2d() {
+        let transforms: [Transform2D<f64>; 5] = [
+            Transform2D::identity(),
+            Transform2D::rotation(Angle::radians(1.0)).then_translate(vec2(5.0, -2.0)),
+            Transform2D::scale(2.0, 0.5).then_rotate(Angle::radians(-2.5)),
+            Transform2D::scale(-1.0, 3.0).then_translate(vec2(1.0, 1.0)),
+            Transform2D::new(1.0, 0.5, 0.25, 2.0, 3.0, 4.0),
+        ];
+        for a in &transforms {
+            for b in &transforms {
+                assert!(a.interpolate(b, 0.0).approx_eq(a));
+                assert!(a.interpolate(b, 1.0).approx_eq(b));
+            }
+        }
+
+        // Rotations are interpolated along the shortest path.
+        let a = Transform2D::rotation(Angle::radians(0.2));
+        let b = Transform2D::rotation(Angle::radians(1.0));
+        assert!(a
+            .interpolate(&b, 0.5)
+            .approx_eq(&Transform2D::rotation(Angle::radians(0.6))));
+        let a = Transform2D::rotation(Angle::radians(0.1));
+        let b = Transform2D::rotation(Angle::radians(2.0 * PI - 0.1));
+        assert!(a.interpolate(&b, 0.5).approx_eq(&Transform2D::identity()));
+
+        // Scales and translations are interpolated linearly.
+        let a = Transform2D::scale(1.0, 1.0).then_translate(vec2(0.0, 2.0));
+        let b = Transform2D::scale(3.0, 5.0).then_translate(vec2(4.0, 0.0));
+        assert!(a
+            .interpolate(&b, 0.5)
+            .approx_eq(&Transform2D::scale(2.0, 3.0).then_translate(vec2(2.0, 1.0))));
+        let a = Transform2D::scale(2.0, 2.0).then_rotate(Angle::radians(0.5));
+        let b = Transform2D::scale(4.0, 4.0).then_rotate(Angle::radians(0.5));
+        assert!(a
+            .interpolate(&b, 0.5)
+            .approx_eq(&Transform2D::scale(3.0, 3.0).then_rotate(Angle::radians(0.5))));
+    }
+
+    #[test]
+    fn test_transform_3d() {
+        let transforms: [Transform3D<f64>; 7] = [
+            Transform3D::identity(),
+            Transform3D::rotation(1.0, 2.0, 3.0, Angle::radians(1.0))
+                .then_translate(vec3(5.0, -2.0, 1.0)),
+            Transform3D::scale(2.0, 0.5, 3.0).then_rotate(0.0, 1.0, 0.0, Angle::radians(-2.5)),
+            Transform3D::scale(-1.0, 3.0, 1.0).then_translate(vec3(1.0, 1.0, 1.0)),
+            Transform3D::rotation(1.0, 0.0, 0.0, Angle::radians(PI)),
+            Transform3D::skew(Angle::radians(0.3), Angle::radians(-0.2)).then_rotate(
+                0.0,
+                0.0,
+                1.0,
+                Angle::radians(0.7),
+            ),
+            Transform3D::rotation(0.0, 1.0, 0.0, Angle::radians(0.4))
+                .then(&Transform3D::perspective(100.0)),
+        ];
+        for a in &transforms {
+            for b in &transforms {
+                assert!(a.interpolate(b, 0.0).approx_eq(a));
+                assert!(a.interpolate(b, 1.0).approx_eq(b));
+            }
+        }
+
+        // Rotations are interpolated like Rotation3D::slerp.
+        let r1 = Rotation3D::around_x(Angle::radians(0.3));
+        let r2 = Rotation3D::around_axis(vec3(1.0, 1.0, 0.0), Angle::radians(2.0));
+        let (a, b) = (r1.to_transform(), r2.to_transform());
+        for &t in &[0.25, 0.5, 0.75] {
+            assert!(a
+                .interpolate(&b, t)
+                .approx_eq(&r1.slerp(&r2, t).to_transform()));
+        }
+
+        // Scales, translations and perspectives are interpolated linearly.
+        let a = Transform3D::scale(1.0, 1.0, 2.0).then_translate(vec3(0.0, 2.0, 0.0));
+        let b = Transform3D::scale(3.0, 5.0, 2.0).then_translate(vec3(4.0, 0.0, -2.0));
+        assert!(a
+            .interpolate(&b, 0.5)
+            .approx_eq(&Transform3D::scale(2.0, 3.0, 2.0).then_translate(vec3(2.0, 1.0, -1.0))));
+        let a = Transform3D::identity();
+        let b = Transform3D::perspective(100.0);
+        assert!(a
+            .interpolate(&b, 0.5)
+            .approx_eq(&Transform3D::perspective(200.0)));
+
+        // Transforms that can't be decomposed are not interpolated.
+        let a = Transform3D::scale(0.0, 1.0, 1.0);
+        let b = Transform3D::identity();
+        assert_eq!(a.interpolate(&b, 0.25), a);
+        assert_eq!(a.interpolate(&b, 0.75), b);
+    }
+}
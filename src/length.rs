@@ -12,6 +12,7 @@ use crate::approxeq::ApproxEq;
 use crate::approxord::{max, min};
 use crate::num::Zero;
 use crate::scale::Scale;
+use crate::UnknownUnit;
 
 use crate::num::One;
 #[cfg(feature = "bytemuck")]
@@ -111,6 +112,18 @@ impl<T: Clone, U> Length<T, U> {
         Length::new(self.0)
     }
 
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(self) -> Length<T, UnknownUnit> {
+        self.cast_unit()
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(l: Length<T, UnknownUnit>) -> Self {
+        l.cast_unit()
+    }
+
     /// Linearly interpolate between this length and another length.
     ///
     /// # Example
@@ -378,6 +391,17 @@ mod tests {
     enum Cm {}
     enum Second {}
 
+    #[test]
+    fn test_untyped() {
+        use crate::UnknownUnit;
+
+        let l: Length<f32, Mm> = Length::new(5.0);
+        let u: Length<f32, UnknownUnit> = l.to_untyped();
+        assert_eq!(u.get(), 5.0);
+        assert_eq!(Length::<f32, Mm>::from_untyped(u), l);
+        assert_eq!(l.cast_unit::<Cm>().get(), 5.0);
+    }
+
     #[cfg(feature = "serde")]
     mod serde {
         use super::*;
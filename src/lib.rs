@@ -44,6 +44,7 @@
 pub use crate::angle::Angle;
 pub use crate::box2d::Box2D;
 pub use crate::homogen::HomogeneousVector;
+pub use crate::interpolate::Interpolate;
 pub use crate::length::Length;
 pub use crate::point::{point2, point3, Point2D, Point3D};
 pub use crate::scale::Scale;
@@ -70,6 +71,7 @@ pub mod approxord;
 mod box2d;
 mod box3d;
 mod homogen;
+mod interpolate;
 mod length;
 pub mod num;
 mod point;
@@ -77,6 +79,8 @@ mod rect;
 mod rigid;
 mod rotation;
 mod scale;
+#[cfg(feature = "serde")]
+pub mod serde;
 mod side_offsets;
 mod size;
 mod transform2d;
@@ -89,6 +93,38 @@ mod vector;
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct UnknownUnit;
 
+/// A unit with a name, which can be appended when displaying values tagged with it.
+///
+/// The name is only used by [`DisplayWithUnits`], the regular `Display`
+/// implementations don't require the unit to implement this trait.
+///
+/// ```rust
+/// use euclid::{size2, Size2D, Unit};
+///
+/// struct Px;
+/// impl Unit for Px {
+///     const NAME: &'static str = "px";
+/// }
+///
+/// let s: Size2D<i32, Px> = size2(30, 40);
+/// assert_eq!(s.to_string(), "30x40");
+/// assert_eq!(s.display_with_units().to_string(), "30x40 px");
+/// ```
+pub trait Unit {
+    /// The name of the unit, or an empty string to display values without a unit.
+    const NAME: &'static str;
+}
+
+impl Unit for UnknownUnit {
+    const NAME: &'static str = "";
+}
+
+/// Displays a value followed by the name of its [`Unit`].
+///
+/// Returned by the `display_with_units` methods.
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayWithUnits<'a, V>(pub(crate) &'a V);
+
 pub mod default {
     //! A set of aliases for all types, tagged with the default unknown unit.
 
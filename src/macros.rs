@@ -28,3 +28,43 @@ macro_rules! mint_vec {
         }
     }
 }
+
+macro_rules! approx_impls {
+    ($name:ident < $($unit:ident),+ > { $($field:ident),+ }) => {
+        #[cfg(feature = "approx")]
+        impl<T, $($unit),+> approx::AbsDiffEq for $name<T, $($unit),+>
+        where
+            T: approx::AbsDiffEq,
+            T::Epsilon: Copy,
+        {
+            type Epsilon = T::Epsilon;
+
+            fn default_epsilon() -> Self::Epsilon {
+                T::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                $( self.$field.abs_diff_eq(&other.$field, epsilon) )&&+
+            }
+        }
+        #[cfg(feature = "approx")]
+        impl<T, $($unit),+> approx::RelativeEq for $name<T, $($unit),+>
+        where
+            T: approx::RelativeEq,
+            T::Epsilon: Copy,
+        {
+            fn default_max_relative() -> Self::Epsilon {
+                T::default_max_relative()
+            }
+
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                $( self.$field.relative_eq(&other.$field, epsilon, max_relative) )&&+
+            }
+        }
+    }
+}
@@ -116,6 +116,8 @@ macro_rules! num_float {
     };
 }
 
+num_int!(i8);
+num_int!(u8);
 num_int!(i16);
 num_int!(u16);
 num_int!(i32);
@@ -126,3 +128,43 @@ num_int!(isize);
 num_int!(usize);
 num_float!(f32);
 num_float!(f64);
+
+/// Defines a total order on floating point values, following the IEEE 754
+/// `totalOrder` predicate.
+///
+/// Unlike `PartialOrd`, every value is ordered, including NaNs: negative NaNs come
+/// first and positive NaNs last, and `-0.0` is lower than `0.0`.
+///
+/// This trait is implemented for `f32` and `f64`, and is sealed so that it can't
+/// be implemented for other types.
+pub trait TotalOrd: Copy + private::Sealed {
+    /// An integer type with the same ordering as the values it is computed from.
+    type Key: Ord + Copy;
+
+    /// Returns a key that orders values according to the `totalOrder` predicate.
+    fn total_order_key(self) -> Self::Key;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! total_ord_float {
+    ($ty:ty, $int:ty, $uint:ty, $bits:expr) => {
+        impl private::Sealed for $ty {}
+        impl TotalOrd for $ty {
+            type Key = $int;
+
+            #[inline]
+            fn total_order_key(self) -> $int {
+                // Flip all the bits but the sign of negative values so that comparing
+                // the bit patterns as signed integers gives the totalOrder result.
+                let bits = self.to_bits() as $int;
+                bits ^ (((bits >> $bits) as $uint) >> 1) as $int
+            }
+        }
+    };
+}
+
+total_ord_float!(f32, i32, u32, 31);
+total_ord_float!(f64, i64, u64, 63);
@@ -8,6 +8,7 @@
 // except according to those terms.
 
 use super::UnknownUnit;
+use crate::angle::Angle;
 use crate::approxeq::ApproxEq;
 use crate::approxord::{max, min};
 use crate::length::Length;
@@ -15,7 +16,7 @@ use crate::num::*;
 use crate::scale::Scale;
 use crate::size::{Size2D, Size3D};
 use crate::vector::{vec2, vec3, Vector2D, Vector3D};
-use core::cmp::{Eq, PartialEq};
+use core::cmp::{Eq, Ordering, PartialEq};
 use core::fmt;
 use core::hash::Hash;
 use core::marker::PhantomData;
@@ -23,7 +24,7 @@ use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAss
 #[cfg(feature = "mint")]
 use mint;
 use num_traits::real::Real;
-use num_traits::{Euclid, Float, NumCast};
+use num_traits::{CheckedAdd, CheckedSub, Euclid, Float, NumCast};
 #[cfg(feature = "serde")]
 use serde;
 
@@ -125,6 +126,7 @@ where
 }
 
 mint_vec!(Point2D[x, y] = Point2);
+approx_impls!(Point2D<U> { x, y });
 
 impl<T: fmt::Debug, U> fmt::Debug for Point2D<T, U> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -335,6 +337,23 @@ impl<T: Copy, U> Point2D<T, U> {
         (self.x, self.y)
     }
 
+    /// Returns an iterator over the coordinates of this point, `x` then `y`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use euclid::{Point2D, point2};
+    /// enum Mm {}
+    ///
+    /// let point: Point2D<_, Mm> = point2(1, -8);
+    ///
+    /// assert_eq!(point.iter().collect::<Vec<_>>(), [1, -8]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = T> {
+        self.to_array().into_iter()
+    }
+
     /// Convert into a 3d point with z-coordinate equals to zero.
     #[inline]
     pub fn to_3d(self) -> Point3D<T, U>
@@ -426,6 +445,26 @@ impl<T: Copy, U> Point2D<T, U> {
         let one_t = T::one() - t;
         point2(one_t * self.x + t * other.x, one_t * self.y + t * other.y)
     }
+
+    /// Samples the quadratic Bézier curve with control points `p0`, `p1` and `p2`
+    /// at `t`, using repeated linear interpolation.
+    #[inline]
+    pub fn quadratic_bezier(p0: Self, p1: Self, p2: Self, t: T) -> Self
+    where
+        T: One + Sub<Output = T> + Mul<Output = T> + Add<Output = T>,
+    {
+        p0.lerp(p1, t).lerp(p1.lerp(p2, t), t)
+    }
+
+    /// Samples the cubic Bézier curve with control points `p0`, `p1`, `p2` and `p3`
+    /// at `t`, using repeated linear interpolation.
+    #[inline]
+    pub fn cubic_bezier(p0: Self, p1: Self, p2: Self, p3: Self, t: T) -> Self
+    where
+        T: One + Sub<Output = T> + Mul<Output = T> + Add<Output = T>,
+    {
+        Self::quadratic_bezier(p0, p1, p2, t).lerp(Self::quadratic_bezier(p1, p2, p3, t), t)
+    }
 }
 
 impl<T: PartialOrd, U> Point2D<T, U> {
@@ -452,6 +491,44 @@ impl<T: PartialOrd, U> Point2D<T, U> {
     }
 }
 
+impl<T: TotalOrd, U> Point2D<T, U> {
+    /// Returns a key that orders points by their x coordinates, then by their
+    /// y coordinates if the x coordinates are equal.
+    ///
+    /// The coordinates are ordered with [`TotalOrd`], so the key is totally ordered
+    /// even in the presence of NaNs, which can be used for deterministic sorting.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use euclid::point2;
+    /// use euclid::default::Point2D;
+    ///
+    /// let mut points: Vec<Point2D<f32>> =
+    ///     vec![point2(1.0, 0.0), point2(f32::NAN, 0.0), point2(0.0, 2.0)];
+    /// points.sort_by_key(|p| p.sort_key());
+    ///
+    /// assert_eq!(points[0], point2(0.0, 2.0));
+    /// assert_eq!(points[1], point2(1.0, 0.0));
+    /// assert!(points[2].x.is_nan());
+    /// ```
+    ///
+    /// [`TotalOrd`]: crate::num::TotalOrd
+    #[inline]
+    pub fn sort_key(&self) -> (T::Key, T::Key) {
+        (self.x.total_order_key(), self.y.total_order_key())
+    }
+
+    /// Compares the x coordinates, then the y coordinates if the x coordinates are equal.
+    ///
+    /// This is a total order even in the presence of NaNs, consistent with
+    /// [`sort_key`](Self::sort_key).
+    #[inline]
+    pub fn cmp_lexicographic(&self, other: &Self) -> Ordering {
+        self.sort_key().cmp(&other.sort_key())
+    }
+}
+
 impl<T: NumCast + Copy, U> Point2D<T, U> {
     /// Cast from one numeric representation to another, preserving the units.
     ///
@@ -543,6 +620,84 @@ impl<T: Copy + Add<T, Output = T>, U> Point2D<T, U> {
     pub fn add_size(self, other: &Size2D<T, U>) -> Self {
         point2(self.x + other.width, self.y + other.height)
     }
+
+    /// Returns this point with its `x` coordinate shifted by `dx`.
+    #[inline]
+    pub fn add_x(self, dx: Length<T, U>) -> Self {
+        point2(self.x + dx.0, self.y)
+    }
+
+    /// Returns this point with its `y` coordinate shifted by `dy`.
+    #[inline]
+    pub fn add_y(self, dy: Length<T, U>) -> Self {
+        point2(self.x, self.y + dy.0)
+    }
+}
+
+impl<T: CheckedAdd + CheckedSub, U> Point2D<T, U> {
+    /// Translates this point by a vector, returning `None` if any component overflows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use euclid::{point2, vec2};
+    /// use euclid::default::Point2D;
+    ///
+    /// let p: Point2D<i32> = point2(1, i32::MAX - 1);
+    ///
+    /// assert_eq!(p.checked_add(vec2(2, 1)), Some(point2(3, i32::MAX)));
+    /// assert_eq!(p.checked_add(vec2(0, 2)), None);
+    /// ```
+    #[inline]
+    pub fn checked_add(self, other: Vector2D<T, U>) -> Option<Self> {
+        Some(point2(
+            self.x.checked_add(&other.x)?,
+            self.y.checked_add(&other.y)?,
+        ))
+    }
+
+    /// Translates this point by the opposite of a vector, returning `None` if any
+    /// component overflows.
+    #[inline]
+    pub fn checked_sub(self, other: Vector2D<T, U>) -> Option<Self> {
+        Some(point2(
+            self.x.checked_sub(&other.x)?,
+            self.y.checked_sub(&other.y)?,
+        ))
+    }
+}
+
+impl<T: Copy + Add<T, Output = T> + Sub<T, Output = T>, U> Point2D<T, U> {
+    /// Returns the point symmetric to this point with respect to `center`.
+    ///
+    /// This is equivalent to a 180° rotation around `center`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use euclid::point2;
+    /// use euclid::default::Point2D;
+    ///
+    /// let p: Point2D<i32> = point2(1, 1);
+    ///
+    /// assert_eq!(p.reflect_across_point(point2(0, 0)), point2(-1, -1));
+    /// assert_eq!(p.reflect_across_point(point2(2, 2)), point2(3, 3));
+    /// ```
+    #[inline]
+    pub fn reflect_across_point(self, center: Self) -> Self {
+        center + (center - self)
+    }
+}
+
+impl<T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<T, Output = T>, U> Point2D<T, U> {
+    /// Returns the squared distance between this point and `other`.
+    ///
+    /// Cheaper than [`distance_to`](Self::distance_to) as it avoids the square
+    /// root, which makes it suitable for comparing distances.
+    #[inline]
+    pub fn square_distance_to(self, other: Self) -> T {
+        (self - other).square_length()
+    }
 }
 
 impl<T: Real + Sub<T, Output = T>, U> Point2D<T, U> {
@@ -817,6 +972,13 @@ impl<T, U> From<(T, T)> for Point2D<T, U> {
     }
 }
 
+impl<T, U> From<(Length<T, U>, Length<T, U>)> for Point2D<T, U> {
+    #[inline]
+    fn from((x, y): (Length<T, U>, Length<T, U>)) -> Self {
+        Point2D::from_lengths(x, y)
+    }
+}
+
 /// A 3d Point tagged with a unit.
 #[repr(C)]
 pub struct Point3D<T, U> {
@@ -828,6 +990,7 @@ pub struct Point3D<T, U> {
 }
 
 mint_vec!(Point3D[x, y, z] = Point3);
+approx_impls!(Point3D<U> { x, y, z });
 
 impl<T: Copy, U> Copy for Point3D<T, U> {}
 
@@ -1239,6 +1402,26 @@ impl<T: Copy, U> Point3D<T, U> {
             one_t * self.z + t * other.z,
         )
     }
+
+    /// Samples the quadratic Bézier curve with control points `p0`, `p1` and `p2`
+    /// at `t`, using repeated linear interpolation.
+    #[inline]
+    pub fn quadratic_bezier(p0: Self, p1: Self, p2: Self, t: T) -> Self
+    where
+        T: One + Sub<Output = T> + Mul<Output = T> + Add<Output = T>,
+    {
+        p0.lerp(p1, t).lerp(p1.lerp(p2, t), t)
+    }
+
+    /// Samples the cubic Bézier curve with control points `p0`, `p1`, `p2` and `p3`
+    /// at `t`, using repeated linear interpolation.
+    #[inline]
+    pub fn cubic_bezier(p0: Self, p1: Self, p2: Self, p3: Self, t: T) -> Self
+    where
+        T: One + Sub<Output = T> + Mul<Output = T> + Add<Output = T>,
+    {
+        Self::quadratic_bezier(p0, p1, p2, t).lerp(Self::quadratic_bezier(p1, p2, p3, t), t)
+    }
 }
 
 impl<T: PartialOrd, U> Point3D<T, U> {
@@ -1308,6 +1491,18 @@ impl<T: NumCast + Copy, U> Point3D<T, U> {
         self.cast()
     }
 
+    /// Cast into an `f32` array padded to 16 bytes, suitable for a `vec3` in a
+    /// GPU uniform block.
+    ///
+    /// The layout is `[x, y, z, 0.0]`: std140 and std430 align a `vec3` like a
+    /// `vec4`, so the fourth component is padding.
+    #[cfg(feature = "bytemuck")]
+    #[inline]
+    pub fn to_gpu_array(self) -> [f32; 4] {
+        let p = self.to_f32();
+        [p.x, p.y, p.z, 0.0]
+    }
+
     /// Cast into an `f64` point.
     #[inline]
     pub fn to_f64(self) -> Point3D<f64, U> {
@@ -1372,6 +1567,43 @@ impl<T: Copy + Add<T, Output = T>, U> Point3D<T, U> {
             self.z + other.depth,
         )
     }
+
+    /// Returns this point with its `x` coordinate shifted by `dx`.
+    #[inline]
+    pub fn add_x(self, dx: Length<T, U>) -> Self {
+        point3(self.x + dx.0, self.y, self.z)
+    }
+
+    /// Returns this point with its `y` coordinate shifted by `dy`.
+    #[inline]
+    pub fn add_y(self, dy: Length<T, U>) -> Self {
+        point3(self.x, self.y + dy.0, self.z)
+    }
+
+    /// Returns this point with its `z` coordinate shifted by `dz`.
+    #[inline]
+    pub fn add_z(self, dz: Length<T, U>) -> Self {
+        point3(self.x, self.y, self.z + dz.0)
+    }
+}
+
+impl<T: Copy + Add<T, Output = T> + Sub<T, Output = T>, U> Point3D<T, U> {
+    /// Returns the point symmetric to this point with respect to `center`.
+    #[inline]
+    pub fn reflect_across_point(self, center: Self) -> Self {
+        center + (center - self)
+    }
+}
+
+impl<T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<T, Output = T>, U> Point3D<T, U> {
+    /// Returns the squared distance between this point and `other`.
+    ///
+    /// Cheaper than [`distance_to`](Self::distance_to) as it avoids the square
+    /// root, which makes it suitable for comparing distances.
+    #[inline]
+    pub fn square_distance_to(self, other: Self) -> T {
+        (self - other).square_length()
+    }
 }
 
 impl<T: Real + Sub<T, Output = T>, U> Point3D<T, U> {
@@ -1379,6 +1611,15 @@ impl<T: Real + Sub<T, Output = T>, U> Point3D<T, U> {
     pub fn distance_to(self, other: Self) -> T {
         (self - other).length()
     }
+
+    /// Rotates this point by `angle` around `axis`, which passes through the origin.
+    ///
+    /// See [`Vector3D::rotate_around_axis`].
+    #[inline]
+    #[must_use]
+    pub fn rotate_around_axis(self, axis: Vector3D<T, U>, angle: Angle<T>) -> Self {
+        self.to_vector().rotate_around_axis(axis, angle).to_point()
+    }
 }
 
 impl<T: Neg, U> Neg for Point3D<T, U> {
@@ -1689,8 +1930,8 @@ pub const fn point3<T, U>(x: T, y: T, z: T) -> Point3D<T, U> {
 
 #[cfg(test)]
 mod point2d {
-    use crate::default::Point2D;
-    use crate::point2;
+    use crate::default::{Length, Point2D};
+    use crate::{point2, vec2};
 
     #[cfg(feature = "mint")]
     use mint;
@@ -1725,6 +1966,140 @@ mod point2d {
         assert_eq!(p1, p2);
     }
 
+    #[test]
+    pub fn test_checked_add_sub() {
+        let p: Point2D<i32> = point2(i32::MAX - 1, i32::MIN + 1);
+
+        assert_eq!(
+            p.checked_add(vec2(1, 0)),
+            Some(point2(i32::MAX, i32::MIN + 1))
+        );
+        assert_eq!(p.checked_add(vec2(2, 0)), None);
+        assert_eq!(p.checked_add(vec2(0, -2)), None);
+        assert_eq!(
+            p.checked_sub(vec2(0, 1)),
+            Some(point2(i32::MAX - 1, i32::MIN))
+        );
+        assert_eq!(p.checked_sub(vec2(0, 2)), None);
+        assert_eq!(p.checked_sub(vec2(-2, 0)), None);
+    }
+
+    #[test]
+    pub fn test_cmp_lexicographic() {
+        use core::cmp::Ordering;
+
+        let nan = f32::NAN;
+        let mut points: Vec<Point2D<f32>> = vec![
+            point2(1.0, nan),
+            point2(nan, 0.0),
+            point2(1.0, -1.0),
+            point2(-nan, 5.0),
+            point2(0.0, 3.0),
+            point2(1.0, -2.0),
+        ];
+        points.sort_by(|a, b| a.cmp_lexicographic(b));
+
+        assert!(points[0].x.is_nan() && points[0].y == 5.0);
+        assert_eq!(points[1], point2(0.0, 3.0));
+        assert_eq!(points[2], point2(1.0, -2.0));
+        assert_eq!(points[3], point2(1.0, -1.0));
+        assert!(points[4].x == 1.0 && points[4].y.is_nan());
+        assert!(points[5].x.is_nan() && points[5].y == 0.0);
+
+        let p: Point2D<f32> = point2(nan, 1.0);
+        assert_eq!(p.cmp_lexicographic(&p), Ordering::Equal);
+        assert_eq!(
+            Point2D::new(-0.0, 1.0).cmp_lexicographic(&point2(0.0, 0.0)),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    pub fn test_sort_key() {
+        let mut points: Vec<Point2D<f64>> = vec![
+            point2(1.0, f64::NAN),
+            point2(1.0, -1.0),
+            point2(-f64::NAN, 5.0),
+            point2(0.0, 3.0),
+            point2(f64::NEG_INFINITY, 0.0),
+        ];
+        let mut sorted = points.clone();
+        sorted.sort_by(|a, b| a.cmp_lexicographic(b));
+        points.sort_by_key(|p| p.sort_key());
+
+        assert!(points[0].x.is_nan() && points[0].y == 5.0);
+        assert_eq!(points[1], point2(f64::NEG_INFINITY, 0.0));
+        assert_eq!(points[2], point2(0.0, 3.0));
+        assert_eq!(points[3], point2(1.0, -1.0));
+        assert!(points[4].x == 1.0 && points[4].y.is_nan());
+        for (a, b) in points.iter().zip(sorted.iter()) {
+            assert_eq!(a.sort_key(), b.sort_key());
+        }
+
+        let p: Point2D<f64> = point2(-0.0, 2.0);
+        assert!(p.sort_key() < Point2D::new(0.0, 1.0).sort_key());
+    }
+
+    #[test]
+    pub fn test_from_lengths_tuple() {
+        let p: Point2D<f32> = (Length::new(1.0), Length::new(2.0)).into();
+        assert_eq!(p, Point2D::new(1.0, 2.0));
+    }
+
+    #[test]
+    pub fn test_reflect_across_point() {
+        let p: Point2D<f32> = point2(1.0, 1.0);
+        assert_eq!(p.reflect_across_point(point2(0.0, 0.0)), point2(-1.0, -1.0));
+        assert_eq!(p.reflect_across_point(point2(2.0, 2.0)), point2(3.0, 3.0));
+        assert_eq!(p.reflect_across_point(p), p);
+    }
+
+    #[test]
+    pub fn test_lerp() {
+        let a: Point2D<f32> = point2(1.0, 2.0);
+        let b: Point2D<f32> = point2(3.0, -4.0);
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(a.lerp(b, 0.5), point2(2.0, -1.0));
+    }
+
+    #[test]
+    pub fn test_bezier() {
+        let p0: Point2D<f32> = point2(0.0, 0.0);
+        let p1: Point2D<f32> = point2(1.0, 2.0);
+        let p2: Point2D<f32> = point2(2.0, 0.0);
+        let p3: Point2D<f32> = point2(3.0, 2.0);
+
+        assert_eq!(Point2D::quadratic_bezier(p0, p1, p2, 0.0), p0);
+        assert_eq!(Point2D::quadratic_bezier(p0, p1, p2, 1.0), p2);
+        assert_eq!(Point2D::quadratic_bezier(p0, p1, p2, 0.5), point2(1.0, 1.0));
+
+        assert_eq!(Point2D::cubic_bezier(p0, p1, p2, p3, 0.0), p0);
+        assert_eq!(Point2D::cubic_bezier(p0, p1, p2, p3, 1.0), p3);
+        assert_eq!(Point2D::cubic_bezier(p0, p1, p2, p3, 0.5), point2(1.5, 1.0));
+    }
+
+    #[test]
+    pub fn test_add_axis() {
+        let p: Point2D<f32> = point2(1.0, 2.0);
+        assert_eq!(p.add_x(Length::new(3.0)), point2(4.0, 2.0));
+        assert_eq!(p.add_y(Length::new(-1.0)), point2(1.0, 1.0));
+    }
+
+    #[test]
+    pub fn test_point_vector_algebra() {
+        use crate::default::Vector2D;
+
+        let a: Point2D<f32> = point2(1.0, 2.0);
+        let b: Point2D<f32> = point2(4.0, 6.0);
+
+        let d: Vector2D<f32> = b - a;
+        assert_eq!(d, vec2(3.0, 4.0));
+        let c: Point2D<f32> = a + d;
+        assert_eq!(c, b);
+        assert_eq!(b - d, a);
+    }
+
     #[test]
     pub fn test_conv_vector() {
         for i in 0..100 {
@@ -1755,6 +2130,28 @@ mod point2d {
         assert_eq!(p1.distance_to(p2), 2.0);
     }
 
+    #[test]
+    pub fn test_square_distance_to() {
+        let p1: Point2D<f32> = Point2D::new(1.0, 1.0);
+        let p2 = Point2D::new(4.0, 5.0);
+
+        assert_eq!(p1.square_distance_to(p2), 25.0);
+        assert_eq!(p1.distance_to(p2), 5.0);
+        assert_eq!(
+            Point2D::new(1, 1).square_distance_to(Point2D::new(4, 5)),
+            25
+        );
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    pub fn test_approx() {
+        let p: Point2D<f32> = point2(0.1 + 0.2, 1.0);
+        approx::assert_abs_diff_eq!(p, point2(0.3, 1.0));
+        approx::assert_relative_eq!(p, point2(0.3, 1.0));
+        approx::assert_relative_ne!(p, point2(0.3, 1.1));
+    }
+
     mod ops {
         use crate::default::Point2D;
         use crate::scale::Scale;
@@ -1981,6 +2378,27 @@ mod point3d {
     #[cfg(feature = "mint")]
     use mint;
 
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    pub fn test_gpu_array() {
+        let p: Point3D<f64> = point3(1.0, 2.0, 3.0);
+        let a = p.to_gpu_array();
+        assert_eq!(a, [1.0, 2.0, 3.0, 0.0]);
+        assert_eq!(core::mem::size_of_val(&a), 16);
+        assert_eq!(bytemuck::cast::<_, [u32; 4]>(a)[3], 0);
+    }
+
+    #[test]
+    pub fn test_rotate_around_axis() {
+        use crate::approxeq::ApproxEq;
+        use crate::{vec3, Angle};
+        use core::f32::consts::FRAC_PI_2;
+
+        let p: Point3D<f32> = point3(1.0, 0.0, 0.0);
+        let r = p.rotate_around_axis(vec3(0.0, 0.0, 1.0), Angle::radians(FRAC_PI_2));
+        assert!(r.approx_eq(&point3(0.0, 1.0, 0.0)));
+    }
+
     #[test]
     pub fn test_min() {
         let p1 = Point3D::new(1.0, 3.0, 5.0);
@@ -2001,6 +2419,49 @@ mod point3d {
         assert_eq!(result, Point3D::new(2.0, 3.0, 5.0));
     }
 
+    #[test]
+    pub fn test_reflect_across_point() {
+        let p: Point3D<i32> = point3(1, 1, -2);
+        assert_eq!(p.reflect_across_point(point3(0, 0, 0)), point3(-1, -1, 2));
+        assert_eq!(p.reflect_across_point(point3(2, 2, 2)), point3(3, 3, 6));
+    }
+
+    #[test]
+    pub fn test_lerp() {
+        let a: Point3D<f32> = point3(1.0, 2.0, 0.0);
+        let b: Point3D<f32> = point3(3.0, -4.0, 5.0);
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(a.lerp(b, 0.5), point3(2.0, -1.0, 2.5));
+    }
+
+    #[test]
+    pub fn test_bezier() {
+        let p0: Point3D<f32> = point3(0.0, 0.0, 0.0);
+        let p1: Point3D<f32> = point3(1.0, 2.0, 4.0);
+        let p2: Point3D<f32> = point3(2.0, 0.0, 0.0);
+        let p3: Point3D<f32> = point3(3.0, 2.0, 4.0);
+
+        assert_eq!(Point3D::quadratic_bezier(p0, p1, p2, 0.0), p0);
+        assert_eq!(Point3D::quadratic_bezier(p0, p1, p2, 1.0), p2);
+        assert_eq!(
+            Point3D::quadratic_bezier(p0, p1, p2, 0.5),
+            point3(1.0, 1.0, 2.0)
+        );
+
+        assert_eq!(Point3D::cubic_bezier(p0, p1, p2, p3, 0.0), p0);
+        assert_eq!(Point3D::cubic_bezier(p0, p1, p2, p3, 1.0), p3);
+    }
+
+    #[test]
+    pub fn test_add_axis() {
+        use crate::default::Length;
+        let p: Point3D<i32> = point3(1, 2, 3);
+        assert_eq!(p.add_x(Length::new(3)), point3(4, 2, 3));
+        assert_eq!(p.add_y(Length::new(-1)), point3(1, 1, 3));
+        assert_eq!(p.add_z(Length::new(5)), point3(1, 2, 8));
+    }
+
     #[test]
     pub fn test_conv_vector() {
         use crate::point3;
@@ -2040,6 +2501,16 @@ mod point3d {
         assert_eq!(p1.distance_to(p2), 3.0);
     }
 
+    #[test]
+    pub fn test_square_distance_to() {
+        // Diagonal of a 2x3x6 box.
+        let p1: Point3D<f32> = Point3D::new(1.0, 1.0, 1.0);
+        let p2 = Point3D::new(3.0, 4.0, 7.0);
+
+        assert_eq!(p1.square_distance_to(p2), 49.0);
+        assert_eq!(p1.distance_to(p2), 7.0);
+    }
+
     #[cfg(feature = "mint")]
     #[test]
     pub fn test_mint() {
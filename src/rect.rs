@@ -7,13 +7,16 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use super::UnknownUnit;
+use super::{DisplayWithUnits, Unit, UnknownUnit};
+use crate::approxeq::ApproxEq;
+use crate::approxord::min;
 use crate::box2d::Box2D;
+use crate::length::Length;
 use crate::num::*;
-use crate::point::Point2D;
+use crate::point::{point2, Point2D};
 use crate::scale::Scale;
 use crate::side_offsets::SideOffsets2D;
-use crate::size::Size2D;
+use crate::size::{size2, Size2D};
 use crate::vector::Vector2D;
 
 #[cfg(feature = "bytemuck")]
@@ -48,7 +51,10 @@ use core::ops::{Add, Div, DivAssign, Mul, MulAssign, Range, Sub};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde",
-    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+    serde(
+        bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"),
+        deny_unknown_fields
+    )
 )]
 pub struct Rect<T, U> {
     pub origin: Point2D<T, U>,
@@ -79,6 +85,8 @@ impl<T: Hash, U> Hash for Rect<T, U> {
     }
 }
 
+approx_impls!(Rect<U> { origin, size });
+
 impl<T: Copy, U> Copy for Rect<T, U> {}
 
 impl<T: Clone, U> Clone for Rect<T, U> {
@@ -105,18 +113,81 @@ impl<T: fmt::Debug, U> fmt::Debug for Rect<T, U> {
     }
 }
 
+impl<T: fmt::Display, U> fmt::Display for Rect<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.size, f)?;
+        write!(f, " at (")?;
+        fmt::Display::fmt(&self.origin.x, f)?;
+        write!(f, ", ")?;
+        fmt::Display::fmt(&self.origin.y, f)?;
+        write!(f, ")")
+    }
+}
+
+impl<T: fmt::Display, U: Unit> fmt::Display for DisplayWithUnits<'_, Rect<T, U>> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if U::NAME.is_empty() {
+            return fmt::Display::fmt(self.0, f);
+        }
+        // Parenthesized so that the unit applies to both the size and the origin.
+        write!(f, "(")?;
+        fmt::Display::fmt(self.0, f)?;
+        write!(f, ") {}", U::NAME)
+    }
+}
+
 impl<T: Default, U> Default for Rect<T, U> {
     fn default() -> Self {
         Rect::new(Default::default(), Default::default())
     }
 }
 
+impl<T: ApproxEq<T>, U> ApproxEq<T> for Rect<T, U> {
+    #[inline]
+    fn approx_epsilon() -> T {
+        T::approx_epsilon()
+    }
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Self, eps: &T) -> bool {
+        self.origin.x.approx_eq_eps(&other.origin.x, eps)
+            && self.origin.y.approx_eq_eps(&other.origin.y, eps)
+            && self.size.width.approx_eq_eps(&other.size.width, eps)
+            && self.size.height.approx_eq_eps(&other.size.height, eps)
+    }
+}
+
 impl<T, U> Rect<T, U> {
     /// Constructor.
     #[inline]
     pub const fn new(origin: Point2D<T, U>, size: Size2D<T, U>) -> Self {
         Rect { origin, size }
     }
+
+    /// Returns an object that displays this rectangle followed by the name of its
+    /// unit, for example `(30x40 at (10, 20)) px`.
+    #[inline]
+    pub fn display_with_units(&self) -> DisplayWithUnits<'_, Self>
+    where
+        U: Unit,
+    {
+        DisplayWithUnits(self)
+    }
+}
+
+impl<T: Copy, U> Rect<T, U> {
+    /// Returns an iterator over the components of this rectangle, in the order
+    /// `origin.x`, `origin.y`, `size.width`, `size.height`.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = T> {
+        [
+            self.origin.x,
+            self.origin.y,
+            self.size.width,
+            self.size.height,
+        ]
+        .into_iter()
+    }
 }
 
 impl<T, U> Rect<T, U>
@@ -207,6 +278,16 @@ where
             max: self.max(),
         }
     }
+
+    /// Calls `f` with each of the four corners of the rectangle, in the order
+    /// `(min_x, min_y)`, `(max_x, min_y)`, `(max_x, max_y)`, `(min_x, max_y)`.
+    pub fn for_each_corner<F: FnMut(Point2D<T, U>)>(&self, mut f: F) {
+        let (min, max) = (self.min(), self.max());
+        f(min);
+        f(Point2D::new(max.x, min.y));
+        f(max);
+        f(Point2D::new(min.x, max.y));
+    }
 }
 
 impl<T, U> Rect<T, U>
@@ -221,10 +302,92 @@ where
         self.to_box2d().contains(p)
     }
 
+    /// Returns `true` if this rectangle contains the point at the given coordinates.
+    ///
+    /// Same as [`contains`] with a point built from `x` and `y`.
+    ///
+    /// [`contains`]: Self::contains
+    #[inline]
+    pub fn contains_length(&self, x: Length<T, U>, y: Length<T, U>) -> bool {
+        self.contains(Point2D::from_lengths(x, y))
+    }
+
     #[inline]
     pub fn intersects(&self, other: &Self) -> bool {
         self.to_box2d().intersects(&other.to_box2d())
     }
+
+    /// Returns the point within the rectangle that is closest to `p`.
+    ///
+    /// Each coordinate of `p` is clamped into the corresponding range of the rectangle,
+    /// edges included. If the rectangle is empty, `p` is returned unchanged.
+    #[inline]
+    pub fn clamp_point(&self, p: Point2D<T, U>) -> Point2D<T, U> {
+        self.to_box2d().clamp_point(p)
+    }
+}
+
+impl<T, U> Rect<T, U>
+where
+    T: Copy + Zero + PartialOrd + Add<T, Output = T> + Sub<T, Output = T> + Mul<T, Output = T>,
+{
+    /// Returns `true` if the segment from `a` to `b` crosses or touches this rectangle.
+    ///
+    /// Segments with an endpoint inside the rectangle are considered intersecting.
+    /// Always returns `false` if the rectangle is empty.
+    pub fn intersects_segment(&self, a: Point2D<T, U>, b: Point2D<T, U>) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+
+        // The segment misses the rectangle if its bounding box does...
+        let segment = Box2D::from_points(&[a, b]);
+        if segment.max.x < self.min_x()
+            || segment.min.x > self.max_x()
+            || segment.max.y < self.min_y()
+            || segment.min.y > self.max_y()
+        {
+            return false;
+        }
+
+        // ...or if all the corners are strictly on the same side of its line.
+        let d = b - a;
+        let (min, max) = (self.min(), self.max());
+        let sides = [
+            d.cross(min - a),
+            d.cross(max - a),
+            d.cross(point2(min.x, max.y) - a),
+            d.cross(point2(max.x, min.y) - a),
+        ];
+        let zero = T::zero();
+        !(sides.iter().all(|&s| s > zero) || sides.iter().all(|&s| s < zero))
+    }
+
+    /// Returns the area of the intersection of two rectangles, or zero if they
+    /// do not intersect.
+    #[inline]
+    pub fn intersection_area(&self, other: &Self) -> T {
+        self.intersection(other).map_or(T::zero(), |r| r.area())
+    }
+
+    /// Returns the intersection over union of two rectangles, that is the area of
+    /// their intersection divided by the area of their union.
+    ///
+    /// The result is between zero for disjoint rectangles and one for identical
+    /// ones. Empty rectangles have no area, and zero is returned if both are empty.
+    pub fn iou(&self, other: &Self) -> T
+    where
+        T: Div<T, Output = T>,
+    {
+        let area = |r: &Self| if r.is_empty() { T::zero() } else { r.area() };
+        let intersection = self.intersection_area(other);
+        let union = area(self) + area(other) - intersection;
+        if union == T::zero() {
+            return T::zero();
+        }
+
+        intersection / union
+    }
 }
 
 impl<T, U> Rect<T, U>
@@ -241,12 +404,49 @@ where
 
         Some(box2d.to_rect())
     }
+
+    /// Computes the intersection of two rectangles without checking whether they
+    /// do intersect.
+    ///
+    /// The result has a negative size if the rectangles do not intersect.
+    #[inline]
+    pub fn intersection_unchecked(&self, other: &Self) -> Self {
+        self.to_box2d()
+            .intersection_unchecked(&other.to_box2d())
+            .to_rect()
+    }
+
+    /// Splits this rectangle with a vertical line at `x`, returning the parts on
+    /// the left and on the right of the line.
+    ///
+    /// See [`Box2D::split_at_x`].
+    #[inline]
+    pub fn split_at_x(&self, x: T) -> (Option<Self>, Option<Self>) {
+        let (left, right) = self.to_box2d().split_at_x(x);
+        (left.map(|b| b.to_rect()), right.map(|b| b.to_rect()))
+    }
+
+    /// Splits this rectangle with a horizontal line at `y`, returning the parts
+    /// above and below the line.
+    ///
+    /// See [`Box2D::split_at_y`].
+    #[inline]
+    pub fn split_at_y(&self, y: T) -> (Option<Self>, Option<Self>) {
+        let (top, bottom) = self.to_box2d().split_at_y(y);
+        (top.map(|b| b.to_rect()), bottom.map(|b| b.to_rect()))
+    }
 }
 
 impl<T, U> Rect<T, U>
 where
     T: Copy + Add<T, Output = T> + Sub<T, Output = T>,
 {
+    /// Inflates the rectangle by the specified sizes on each side respectively.
+    ///
+    /// Inflating a non-empty rectangle by non-negative amounts always produces a
+    /// non-empty rectangle, so the result of [`to_non_empty`] is preserved.
+    ///
+    /// [`to_non_empty`]: Self::to_non_empty
     #[inline]
     #[must_use]
     pub fn inflate(&self, width: T, height: T) -> Self {
@@ -275,6 +475,21 @@ where
                 && self.min_y() <= rect.min_y()
                 && rect.max_y() <= self.max_y())
     }
+
+    /// Returns `true` if this rectangle contains `rect` and `rect` is not empty.
+    ///
+    /// Unlike [`contains_rect`], which considers an empty `rect` to be contained
+    /// in any rectangle, this always returns `false` if `rect` is empty.
+    ///
+    /// [`contains_rect`]: Self::contains_rect
+    #[inline]
+    pub fn contains_rect_exclusive(&self, rect: &Self) -> bool {
+        !rect.is_empty()
+            && self.min_x() <= rect.min_x()
+            && rect.max_x() <= self.max_x()
+            && self.min_y() <= rect.min_y()
+            && rect.max_y() <= self.max_y()
+    }
 }
 
 impl<T, U> Rect<T, U>
@@ -361,16 +576,127 @@ impl<T, U> Rect<T, U>
 where
     T: Copy + One + Add<Output = T> + Div<Output = T>,
 {
+    /// Returns the center of the rectangle.
+    ///
+    /// With integer coordinates, the half size is truncated.
     pub fn center(&self) -> Point2D<T, U> {
         let two = T::one() + T::one();
         self.origin + self.size.to_vector() / two
     }
 }
 
+impl<T, U> Rect<T, U>
+where
+    T: Copy + One + Add<Output = T> + Sub<Output = T> + Div<Output = T>,
+{
+    /// Splits the rectangle into four quadrants, in the following order:
+    /// top-left, top-right, bottom-left, bottom-right (assuming a y-down
+    /// coordinate system, "top" is the side of the origin).
+    ///
+    /// The quadrants meet at [`center`]. With integer coordinates, the half size
+    /// is truncated, so for odd dimensions the right and bottom quadrants are one
+    /// unit wider or taller than the left and top ones. The quadrants always cover
+    /// exactly the original rectangle.
+    ///
+    /// [`center`]: Self::center
+    pub fn quadrants(&self) -> [Self; 4] {
+        let two = T::one() + T::one();
+        let half = self.size / two;
+        let rest = self.size - half;
+        let (x0, y0) = (self.origin.x, self.origin.y);
+        let (x1, y1) = (x0 + half.width, y0 + half.height);
+        [
+            Rect::new(point2(x0, y0), half),
+            Rect::new(point2(x1, y0), size2(rest.width, half.height)),
+            Rect::new(point2(x0, y1), size2(half.width, rest.height)),
+            Rect::new(point2(x1, y1), rest),
+        ]
+    }
+}
+
+impl<T, U> Rect<T, U>
+where
+    T: Copy + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Returns this rectangle scaled by `factor` about its center.
+    ///
+    /// The center of the returned rectangle is the same as the center of `self`.
+    #[inline]
+    #[must_use]
+    pub fn scale_about_center(&self, factor: T) -> Self {
+        let center = self.center();
+        Rect::new(center + (self.origin - center) * factor, self.size * factor)
+    }
+}
+
+impl<T, U> Rect<T, U>
+where
+    T: Copy
+        + One
+        + PartialOrd
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>,
+{
+    /// Returns this rectangle scaled to fit inside `container` while preserving its
+    /// aspect ratio, and centered in `container`.
+    ///
+    /// The origin of `self` is ignored. The result touches either both vertical or
+    /// both horizontal edges of `container`, leaving equal space on the other sides.
+    ///
+    /// The size of `self` must not be empty.
+    #[must_use]
+    pub fn fit_into(&self, container: Self) -> Self {
+        let scale = min(
+            container.size.width / self.size.width,
+            container.size.height / self.size.height,
+        );
+        let size = self.size * scale;
+        let two = T::one() + T::one();
+        let origin = container.center() - size.to_vector() / two;
+
+        Rect::new(origin, size)
+    }
+
+    /// Returns the smallest rectangle with the same center as `self` and a
+    /// `width / height` ratio equal to `ratio`.
+    ///
+    /// Either the width or the height is enlarged, never both, and the rectangle
+    /// is never shrunk.
+    ///
+    /// The size of `self` must not be empty.
+    #[must_use]
+    pub fn expand_to_aspect_ratio(&self, ratio: T) -> Self {
+        let size = if self.size.width < self.size.height * ratio {
+            Size2D::new(self.size.height * ratio, self.size.height)
+        } else {
+            Size2D::new(self.size.width, self.size.width / ratio)
+        };
+        let two = T::one() + T::one();
+        let origin = self.center() - size.to_vector() / two;
+
+        Rect::new(origin, size)
+    }
+
+    /// Returns the smallest square with the same center as `self` that contains it.
+    ///
+    /// The shorter side is enlarged to match the longer one.
+    #[must_use]
+    pub fn bounding_square(&self) -> Self {
+        self.expand_to_aspect_ratio(T::one())
+    }
+}
+
 impl<T, U> Rect<T, U>
 where
     T: Copy + PartialOrd + Add<T, Output = T> + Sub<T, Output = T> + Zero,
 {
+    /// Computes the union of two rectangles.
+    ///
+    /// Empty rectangles, including rectangles with a negative size, are ignored:
+    /// if either of the rectangles is empty, the other one is returned.
+    /// This makes the union associative and an empty rectangle its identity element.
     #[inline]
     pub fn union(&self, other: &Self) -> Self {
         self.to_box2d().union(&other.to_box2d()).to_rect()
@@ -391,6 +717,10 @@ impl<T, U> Rect<T, U> {
 }
 
 impl<T: Copy + Mul<T, Output = T>, U> Rect<T, U> {
+    /// Returns the area of the rectangle.
+    ///
+    /// Scaling a rectangle scales its area quadratically: `(rect * scale).area()`
+    /// is equal to `rect.area() * scale.get() * scale.get()`.
     #[inline]
     pub fn area(&self) -> T {
         self.size.area()
@@ -402,6 +732,16 @@ impl<T: Copy + Zero + PartialOrd, U> Rect<T, U> {
     pub fn is_empty(&self) -> bool {
         self.size.is_empty()
     }
+
+    /// Returns `true` if the width or height of the rectangle is not greater
+    /// than `eps`.
+    ///
+    /// Like [`is_empty`](Self::is_empty), but treats slivers thinner than `eps`
+    /// as empty.
+    #[inline]
+    pub fn is_empty_eps(&self, eps: T) -> bool {
+        !(self.size.width > eps && self.size.height > eps)
+    }
 }
 
 impl<T: Copy + Zero + PartialOrd, U> Rect<T, U> {
@@ -543,6 +883,17 @@ impl<T: NumCast + Copy, U> Rect<T, U> {
         self.cast()
     }
 
+    /// Cast into an `f32` array suitable for a `vec4` in a GPU uniform block.
+    ///
+    /// The layout is `[x, y, width, height]`. It is already 16 bytes long, so
+    /// there is no padding.
+    #[cfg(feature = "bytemuck")]
+    #[inline]
+    pub fn to_gpu_array(&self) -> [f32; 4] {
+        let r = self.to_f32();
+        [r.origin.x, r.origin.y, r.size.width, r.size.height]
+    }
+
     /// Cast into an `f64` rectangle.
     #[inline]
     pub fn to_f64(&self) -> Rect<f64, U> {
@@ -631,6 +982,9 @@ impl<T: Floor + Ceil + Round + Add<T, Output = T> + Sub<T, Output = T>, U> Rect<
     /// Return a rectangle with edges rounded to integer coordinates, such that
     /// the original rectangle contains the resulting rectangle.
     ///
+    /// The result can be empty even if `self` isn't, combine with
+    /// [`Rect::to_non_empty`] when that matters.
+    ///
     /// # Usage notes
     /// Note, that when using with floating-point `T` types that method can significantly
     /// lose precision for large values, so if you need to call this method very often it
@@ -643,6 +997,9 @@ impl<T: Floor + Ceil + Round + Add<T, Output = T> + Sub<T, Output = T>, U> Rect<
     /// Return a rectangle with edges rounded to integer coordinates, such that
     /// the original rectangle is contained in the resulting rectangle.
     ///
+    /// Rounding out never shrinks the rectangle, so the result of rounding out
+    /// a non-empty rectangle is non-empty.
+    ///
     /// # Usage notes
     /// Note, that when using with floating-point `T` types that method can significantly
     /// lose precision for large values, so if you need to call this method very often it
@@ -651,6 +1008,50 @@ impl<T: Floor + Ceil + Round + Add<T, Output = T> + Sub<T, Output = T>, U> Rect<
     pub fn round_out(&self) -> Self {
         self.to_box2d().round_out().to_rect()
     }
+
+    /// Scales this rectangle into device space and rounds it to the device pixel grid
+    /// with [`round`].
+    ///
+    /// [`round`]: Self::round
+    #[must_use]
+    pub fn snap_to_device_pixels<U2>(&self, scale: Scale<T, U, U2>) -> Rect<T, U2>
+    where
+        T: Copy + Mul<T, Output = T>,
+    {
+        (*self * scale).round()
+    }
+}
+
+impl<T, U> Rect<T, U>
+where
+    T: Floor + Ceil + Round + Add<T, Output = T> + Sub<T, Output = T> + NumCast,
+{
+    /// Round with [`round`] and cast into an `i32` rectangle.
+    ///
+    /// [`round`]: Self::round
+    #[inline]
+    pub fn to_i32_round(&self) -> Rect<i32, U> {
+        self.round().to_i32()
+    }
+
+    /// Round with [`round_in`] and cast into an `i32` rectangle.
+    ///
+    /// [`round_in`]: Self::round_in
+    #[inline]
+    pub fn to_i32_round_in(&self) -> Rect<i32, U> {
+        self.round_in().to_i32()
+    }
+
+    /// Round with [`round_out`] and cast into an `i32` rectangle.
+    ///
+    /// This is usually the right conversion from a floating point rectangle to
+    /// integer device pixels, as it doesn't clip any part of the original rectangle.
+    ///
+    /// [`round_out`]: Self::round_out
+    #[inline]
+    pub fn to_i32_round_out(&self) -> Rect<i32, U> {
+        self.round_out().to_i32()
+    }
 }
 
 impl<T, U> From<Size2D<T, U>> for Rect<T, U>
@@ -669,7 +1070,7 @@ pub const fn rect<T, U>(x: T, y: T, w: T, h: T) -> Rect<T, U> {
 
 #[cfg(test)]
 mod tests {
-    use crate::default::{Point2D, Rect, Size2D};
+    use crate::default::{Length, Point2D, Rect, Scale, Size2D};
     use crate::side_offsets::SideOffsets2D;
     use crate::{point2, rect, size2, vec2};
 
@@ -712,6 +1113,30 @@ mod tests {
         assert!(ps.size == Size2D::new(270, 200));
     }
 
+    #[test]
+    fn test_union_empty_and_associativity() {
+        let rects: [Rect<i32>; 6] = [
+            rect(0, 0, 50, 40),
+            rect(20, 20, 5, 5),
+            rect(-15, -30, 200, 15),
+            rect(100, 100, 0, 0),
+            rect(-100, 10, 0, 20),
+            rect(30, 30, -10, -20),
+        ];
+
+        for a in &rects {
+            for b in &rects {
+                if b.is_empty() {
+                    assert_eq!(a.union(b), *a);
+                    assert_eq!(b.union(a), if a.is_empty() { *b } else { *a });
+                }
+                for c in &rects {
+                    assert_eq!(a.union(b).union(c), a.union(&b.union(c)));
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_intersection() {
         let p = Rect::new(Point2D::new(0, 0), Size2D::new(10, 20));
@@ -796,6 +1221,16 @@ mod tests {
         assert!(r.contains_rect(&Rect::new(p, Size2D::zero())));
     }
 
+    #[test]
+    fn test_contains_length() {
+        let r = Rect::new(Point2D::new(-20, 15), Size2D::new(100, 200));
+
+        assert!(r.contains_length(Length::new(0), Length::new(50)));
+        assert!(r.contains_length(Length::new(-20), Length::new(15)));
+        assert!(!r.contains_length(Length::new(80), Length::new(50)));
+        assert!(!r.contains_length(Length::new(0), Length::new(10)));
+    }
+
     #[test]
     fn test_scale() {
         let p = Rect::new(Point2D::new(0u32, 0u32), Size2D::new(50u32, 40u32));
@@ -834,6 +1269,158 @@ mod tests {
         assert!(rr.origin.y == 5);
     }
 
+    #[test]
+    fn test_inflate_non_empty() {
+        let r: Rect<f32> = rect(1.0, 2.0, 0.5, 0.25);
+        let inflated = r.to_non_empty().unwrap().inflate(0.0, 3.0);
+        assert_eq!(inflated.to_non_empty(), Some(rect(1.0, -1.0, 0.5, 6.25)));
+        assert!(r.inflate(2.0, 0.0).to_non_empty().is_some());
+    }
+
+    #[test]
+    fn test_intersection_unchecked() {
+        let r1: Rect<i32> = rect(0, 0, 10, 10);
+        let r2: Rect<i32> = rect(5, -5, 10, 10);
+        assert_eq!(
+            r1.intersection_unchecked(&r2),
+            r1.intersection(&r2).unwrap()
+        );
+        assert_eq!(r1.intersection_area(&r2), 25);
+
+        let r3: Rect<i32> = rect(20, 0, 10, 10);
+        assert!(r1.intersection_unchecked(&r3).size.width < 0);
+        assert_eq!(r1.intersection_area(&r3), 0);
+    }
+
+    #[test]
+    fn test_snap_to_device_pixels() {
+        let r: Rect<f32> = rect(0.3, 0.7, 10.2, 5.1);
+        let snapped = r.snap_to_device_pixels(Scale::new(2.0));
+        assert_eq!(snapped, rect(1.0, 1.0, 20.0, 11.0));
+        assert_eq!(snapped, snapped.round());
+    }
+
+    #[test]
+    fn test_clamp_point() {
+        let r: Rect<i32> = rect(10, 20, 30, 40);
+
+        // Inside.
+        assert_eq!(r.clamp_point(point2(15, 25)), point2(15, 25));
+        // Left of the rectangle.
+        assert_eq!(r.clamp_point(point2(0, 30)), point2(10, 30));
+        // Beyond the bottom-right corner.
+        assert_eq!(r.clamp_point(point2(100, 100)), point2(40, 60));
+        // Empty rectangle.
+        let empty: Rect<i32> = rect(10, 20, 0, 40);
+        assert_eq!(empty.clamp_point(point2(0, 0)), point2(0, 0));
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_gpu_array() {
+        let r: Rect<i32> = rect(1, 2, 3, 4);
+        let a = r.to_gpu_array();
+        assert_eq!(a, [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(core::mem::size_of_val(&a), 16);
+    }
+
+    #[test]
+    fn test_iou() {
+        let r: Rect<f32> = rect(0.0, 0.0, 10.0, 10.0);
+        assert_eq!(r.iou(&r), 1.0);
+        assert_eq!(r.iou(&rect(20.0, 0.0, 10.0, 10.0)), 0.0);
+        // Half of each rectangle overlaps the other.
+        assert_eq!(r.iou(&rect(5.0, 0.0, 10.0, 10.0)), 50.0 / 150.0);
+        // Empty rectangles.
+        let empty: Rect<f32> = rect(0.0, 0.0, 0.0, 10.0);
+        assert_eq!(empty.iou(&empty), 0.0);
+        assert_eq!(empty.iou(&r), 0.0);
+    }
+
+    #[test]
+    fn test_display() {
+        struct Px;
+        impl crate::Unit for Px {
+            const NAME: &'static str = "px";
+        }
+
+        let r: crate::Rect<i32, Px> = rect(10, 20, 30, 40);
+        assert_eq!(format!("{}", r), "30x40 at (10, 20)");
+        assert_eq!(
+            format!("{}", r.display_with_units()),
+            "(30x40 at (10, 20)) px"
+        );
+        let r: Rect<f32> = rect(0.5, 1.0, 2.0, 3.0);
+        assert_eq!(format!("{}", r), "2x3 at (0.5, 1)");
+        assert_eq!(format!("{}", r.display_with_units()), "2x3 at (0.5, 1)");
+
+        // Units don't need to implement `Unit` to be displayed.
+        struct Mm;
+        let r: crate::Rect<i32, Mm> = rect(1, 2, 3, 4);
+        assert_eq!(format!("{}", r), "3x4 at (1, 2)");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_unknown_field() {
+        use serde_test::{assert_de_tokens_error, Token};
+
+        assert_de_tokens_error::<Rect<f32>>(
+            &[
+                Token::Struct {
+                    name: "Rect",
+                    len: 2,
+                },
+                Token::Str("orign"),
+            ],
+            "unknown field `orign`, expected `origin` or `size`",
+        );
+    }
+
+    #[test]
+    fn test_round_integer() {
+        let r: Rect<i32> = rect(-3, 2, 5, 7);
+        assert_eq!(r.round(), r);
+        assert_eq!(r.round_in(), r);
+        assert_eq!(r.round_out(), r);
+
+        let r: Rect<u8> = rect(3, 2, 5, 7);
+        assert_eq!(r.round_out(), r);
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn test_approx() {
+        let r: Rect<f64> = rect(0.1 + 0.2, 0.0, 10.0, 0.7 * 3.0);
+        approx::assert_relative_eq!(r, rect(0.3, 0.0, 10.0, 2.1));
+        approx::assert_relative_ne!(r, rect(0.3, 0.0, 10.0, 2.2));
+    }
+
+    #[test]
+    fn test_split() {
+        let r: Rect<i32> = rect(0, 0, 10, 20);
+
+        // Inside.
+        assert_eq!(
+            r.split_at_x(4),
+            (Some(rect(0, 0, 4, 20)), Some(rect(4, 0, 6, 20)))
+        );
+        assert_eq!(
+            r.split_at_y(5),
+            (Some(rect(0, 0, 10, 5)), Some(rect(0, 5, 10, 15)))
+        );
+
+        // On an edge.
+        assert_eq!(r.split_at_x(0), (None, Some(r)));
+        assert_eq!(r.split_at_y(20), (Some(r), None));
+
+        // Outside.
+        assert_eq!(r.split_at_x(-5), (None, Some(r)));
+        assert_eq!(r.split_at_x(15), (Some(r), None));
+        assert_eq!(r.split_at_y(-5), (None, Some(r)));
+        assert_eq!(r.split_at_y(25), (Some(r), None));
+    }
+
     #[test]
     fn test_inner_outer_rect() {
         let inner_rect = Rect::new(point2(20, 40), size2(80, 100));
@@ -911,6 +1498,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_round_non_empty() {
+        let r: Rect<f32> = rect(0.2, 0.2, 0.5, 0.5);
+        assert!(r.to_non_empty().is_some());
+        assert!(r.round_out().to_non_empty().is_some());
+        assert!(r.round_in().to_non_empty().is_none());
+
+        let r: Rect<f32> = rect(-0.7, 1.3, 2.9, 2.1);
+        assert!(r.round_out().to_non_empty().is_some());
+        assert!(r.round_in().to_non_empty().is_some());
+    }
+
+    #[test]
+    fn test_to_i32_round() {
+        let r: Rect<f32> = rect(-0.7, 1.3, 2.9, 2.1);
+        assert_eq!(r.to_i32_round(), r.round().to_i32());
+        assert_eq!(r.to_i32_round_in(), r.round_in().to_i32());
+        assert_eq!(r.to_i32_round_out(), r.round_out().to_i32());
+        assert_eq!(r.to_i32_round_out(), rect(-1, 1, 4, 3));
+        assert_eq!(r.to_i32_round_in(), rect(0, 2, 2, 1));
+    }
+
+    #[test]
+    fn test_quadrants() {
+        let r: Rect<f32> = rect(1.0, 2.0, 4.0, 6.0);
+        let q = r.quadrants();
+        assert_eq!(q[0], rect(1.0, 2.0, 2.0, 3.0));
+        assert_eq!(q[1], rect(3.0, 2.0, 2.0, 3.0));
+        assert_eq!(q[2], rect(1.0, 5.0, 2.0, 3.0));
+        assert_eq!(q[3], rect(3.0, 5.0, 2.0, 3.0));
+
+        for r in [rect(-2, 5, 4, 10), rect(0, 0, 5, 7)] {
+            let q: [Rect<i32>; 4] = r.quadrants();
+            let union = q.iter().fold(q[0], |acc, q| acc.union(q));
+            assert_eq!(union, r);
+            let area: i32 = q.iter().map(|q| q.area()).sum();
+            assert_eq!(area, r.area());
+            for i in 0..4 {
+                for j in i + 1..4 {
+                    assert!(!q[i].intersects(&q[j]));
+                }
+            }
+        }
+
+        // Odd dimensions give the extra unit to the right and bottom quadrants.
+        let q = Rect::new(point2(0, 0), size2(5, 7)).quadrants();
+        assert_eq!(q[0].size, size2(2, 3));
+        assert_eq!(q[3].size, size2(3, 4));
+    }
+
     #[test]
     fn test_center() {
         let r: Rect<i32> = rect(-2, 5, 4, 10);
@@ -920,6 +1557,204 @@ mod tests {
         assert_eq!(r.center(), point2(2.5, 4.0));
     }
 
+    #[test]
+    fn test_center_truncates() {
+        let r: Rect<i32> = rect(0, 0, 10, 20);
+        assert_eq!(r.center(), point2(5, 10));
+
+        // Integer division truncates.
+        let r: Rect<i32> = rect(-3, 0, 5, 3);
+        assert_eq!(r.center(), point2(-1, 1));
+    }
+
+    #[test]
+    fn test_fit_into() {
+        let container: Rect<f32> = rect(10.0, 20.0, 100.0, 100.0);
+
+        let r: Rect<f32> = rect(-5.0, 3.0, 2.0, 1.0);
+        assert_eq!(r.fit_into(container), rect(10.0, 45.0, 100.0, 50.0));
+
+        let r: Rect<f32> = rect(0.0, 0.0, 10.0, 40.0);
+        assert_eq!(r.fit_into(container), rect(47.5, 20.0, 25.0, 100.0));
+
+        assert_eq!(container.fit_into(container), container);
+    }
+
+    #[test]
+    fn test_expand_to_aspect_ratio() {
+        let r: Rect<f32> = rect(0.0, 0.0, 90.0, 90.0);
+        assert_eq!(
+            r.expand_to_aspect_ratio(16.0 / 9.0),
+            rect(-35.0, 0.0, 160.0, 90.0)
+        );
+
+        let r: Rect<f32> = rect(10.0, 10.0, 160.0, 40.0);
+        assert_eq!(
+            r.expand_to_aspect_ratio(16.0 / 9.0),
+            rect(10.0, -15.0, 160.0, 90.0)
+        );
+
+        let r: Rect<f32> = rect(1.0, 2.0, 20.0, 10.0);
+        assert_eq!(r.expand_to_aspect_ratio(2.0), r);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(Rect::<f32>::default(), Rect::zero());
+        assert_eq!(Rect::<i32>::default(), Rect::zero());
+        assert_eq!(Size2D::<f32>::default(), Size2D::zero());
+        assert_eq!(Point2D::<i32>::default(), Point2D::zero());
+    }
+
+    #[test]
+    fn test_scaled_area() {
+        let r: Rect<f32> = rect(1.0, 2.0, 3.0, 4.0);
+        let s: Scale<f32> = Scale::new(2.5);
+        let scaled = r * s;
+        assert_eq!(scaled.area(), r.area() * s.get() * s.get());
+        assert_eq!(scaled.area(), 75.0);
+    }
+
+    #[test]
+    fn test_intersects_segment() {
+        let r: Rect<f32> = rect(0.0, 0.0, 10.0, 10.0);
+
+        // Crossing the rectangle.
+        assert!(r.intersects_segment(point2(-5.0, 5.0), point2(15.0, 5.0)));
+        assert!(r.intersects_segment(point2(-5.0, -5.0), point2(15.0, 15.0)));
+        // Entirely inside.
+        assert!(r.intersects_segment(point2(2.0, 2.0), point2(8.0, 3.0)));
+        // One endpoint inside.
+        assert!(r.intersects_segment(point2(5.0, 5.0), point2(20.0, 30.0)));
+        // Missing the rectangle.
+        assert!(!r.intersects_segment(point2(-5.0, -5.0), point2(-1.0, 20.0)));
+        assert!(!r.intersects_segment(point2(11.0, 0.0), point2(11.0, 10.0)));
+        assert!(!r.intersects_segment(point2(8.0, -5.0), point2(15.0, 2.0)));
+        // Stopping short of the rectangle.
+        assert!(!r.intersects_segment(point2(-10.0, 5.0), point2(-1.0, 5.0)));
+
+        assert!(!Rect::zero().intersects_segment(point2(-1.0, -1.0), point2(1.0, 1.0)));
+    }
+
+    #[test]
+    fn test_intersects_segment_integer() {
+        let r: Rect<i32> = rect(0, 0, 10, 10);
+
+        assert!(r.intersects_segment(point2(-5, 5), point2(15, 5)));
+        assert!(r.intersects_segment(point2(2, 2), point2(8, 3)));
+        // Touching a corner.
+        assert!(r.intersects_segment(point2(-5, 15), point2(0, 10)));
+        assert!(r.intersects_segment(point2(-1, 11), point2(1, 9)));
+        // Passing close to a corner.
+        assert!(!r.intersects_segment(point2(-1, 10), point2(2, 13)));
+        assert!(!r.intersects_segment(point2(8, -5), point2(15, 2)));
+        assert!(!r.intersects_segment(point2(-10, 5), point2(-1, 5)));
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        use crate::approxeq::ApproxEq;
+
+        let r: Rect<f32> = rect(1.0, 2.0, 3.0, 4.0);
+        assert!(r.approx_eq(&rect(1.0 + 1e-7, 2.0, 3.0, 4.0 - 1e-7)));
+        assert!(!r.approx_eq(&rect(1.0, 2.0, 3.1, 4.0)));
+        assert!(r.approx_eq_eps(&rect(1.0, 2.0, 3.1, 4.0), &0.2));
+    }
+
+    #[test]
+    fn test_is_empty_eps() {
+        let sliver: Rect<f32> = rect(0.0, 0.0, 10.0, 0.001);
+        assert!(!sliver.is_empty());
+        assert!(sliver.is_empty_eps(0.01));
+        assert!(!sliver.is_empty_eps(0.0001));
+        assert!(Rect::new(Point2D::zero(), Size2D::new(-1.0, 5.0)).is_empty_eps(0.0));
+    }
+
+    #[test]
+    fn test_integer_ops() {
+        // Comparison based operations only require `PartialOrd`, not `Float`.
+        let a: Point2D<i32> = point2(1, 5);
+        let b: Point2D<i32> = point2(3, 2);
+        assert_eq!(a.min(b), point2(1, 2));
+        assert_eq!(a.max(b), point2(3, 5));
+        assert_eq!(point2(-4, 9).clamp(a.min(b), a.max(b)), point2(1, 5));
+
+        let s: Size2D<i32> = size2(3, 8);
+        assert_eq!(s.min(size2(5, 2)), size2(3, 2));
+        assert_eq!(s.max(size2(5, 2)), size2(5, 8));
+
+        let r1: Rect<i32> = rect(0, 0, 10, 10);
+        let r2: Rect<i32> = rect(5, -5, 10, 10);
+        assert_eq!(r1.intersection(&r2), Some(rect(5, 0, 5, 5)));
+        assert_eq!(r1.union(&r2), rect(0, -5, 15, 15));
+        assert!(r1.contains(point2(9, 9)));
+        assert_eq!(r1.fit_into(r2), rect(5, -5, 10, 10));
+    }
+
+    #[test]
+    fn test_contains_rect_exclusive() {
+        let r: Rect<f32> = rect(0.0, 0.0, 10.0, 10.0);
+        let inner: Rect<f32> = rect(2.0, 2.0, 3.0, 3.0);
+        assert!(r.contains_rect(&inner));
+        assert!(r.contains_rect_exclusive(&inner));
+
+        // The two methods disagree on empty rectangles.
+        let empty: Rect<f32> = rect(2.0, 2.0, 0.0, 0.0);
+        assert!(r.contains_rect(&empty));
+        assert!(!r.contains_rect_exclusive(&empty));
+
+        let outside: Rect<f32> = rect(8.0, 8.0, 3.0, 3.0);
+        assert!(!r.contains_rect(&outside));
+        assert!(!r.contains_rect_exclusive(&outside));
+    }
+
+    #[test]
+    fn test_bounding_square() {
+        let r: Rect<f32> = rect(0.0, 3.0, 10.0, 4.0);
+        let sq = r.bounding_square();
+        assert_eq!(sq, rect(0.0, 0.0, 10.0, 10.0));
+        assert_eq!(sq.center(), r.center());
+
+        let r: Rect<f32> = rect(1.0, 1.0, 2.0, 6.0);
+        assert_eq!(r.bounding_square(), rect(-1.0, 1.0, 6.0, 6.0));
+    }
+
+    #[test]
+    fn test_scale_about_center() {
+        let r: Rect<f32> = rect(1.0, 2.0, 4.0, 6.0);
+        let scaled = r.scale_about_center(2.0);
+        assert_eq!(scaled.center(), r.center());
+        assert_eq!(scaled.size, size2(8.0, 12.0));
+        assert_eq!(scaled, rect(-1.0, -1.0, 8.0, 12.0));
+
+        assert_eq!(r.scale_about_center(0.5), rect(2.0, 3.5, 2.0, 3.0));
+    }
+
+    #[test]
+    fn test_iter() {
+        let r: Rect<i32> = rect(1, 2, 3, 4);
+        assert_eq!(r.iter().collect::<Vec<_>>(), [1, 2, 3, 4]);
+        assert_eq!(r.origin.iter().collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(r.size.iter().collect::<Vec<_>>(), [3, 4]);
+    }
+
+    #[test]
+    fn test_for_each_corner() {
+        let r: Rect<f32> = rect(1.0, 2.0, 3.0, 4.0);
+        let mut corners = Vec::new();
+        r.for_each_corner(|p| corners.push(p));
+        assert_eq!(corners.len(), 4);
+        assert_eq!(
+            corners,
+            vec![
+                point2(1.0, 2.0),
+                point2(4.0, 2.0),
+                point2(4.0, 6.0),
+                point2(1.0, 6.0),
+            ]
+        );
+    }
+
     #[test]
     fn test_nan() {
         let r1: Rect<f32> = rect(-2.0, 5.0, 4.0, std::f32::NAN);
@@ -821,6 +821,38 @@ fn simple_rotation_2d() {
         .approx_eq(&r90.transform_point(point2(1.0, 2.0))));
 }
 
+#[test]
+fn rotation_2d_then() {
+    use crate::default::Rotation2D;
+    use core::f32::consts::{FRAC_PI_2, PI};
+
+    let r1 = Rotation2D::radians(PI);
+    let r2 = Rotation2D::radians(3.0 * FRAC_PI_2);
+    let r = r1.then(&r2);
+
+    // Composition adds the angles, modulo 2π.
+    assert!(r
+        .get_angle()
+        .positive()
+        .approx_eq(&Angle::radians(FRAC_PI_2)));
+    assert!(r
+        .transform_point(point2(1.0, 2.0))
+        .approx_eq(&r1.transform_point(r2.transform_point(point2(1.0, 2.0)))));
+}
+
+#[test]
+fn rotation_2d_to_transform() {
+    use crate::default::{Rotation2D, Transform2D};
+
+    let angle = Angle::radians(0.7f32);
+    let r = Rotation2D::new(angle);
+    let m = r.to_transform();
+    assert_eq!(m, Transform2D::rotation(angle));
+    assert!(m
+        .transform_point(point2(1.0, 2.0))
+        .approx_eq(&r.transform_point(point2(1.0, 2.0))));
+}
+
 #[test]
 fn simple_rotation_3d_in_2d() {
     use crate::default::Rotation3D;
@@ -851,6 +883,29 @@ fn simple_rotation_3d_in_2d() {
         .approx_eq(&r90.transform_point2d(point2(1.0, 2.0))));
 }
 
+#[test]
+fn transform_3d() {
+    use crate::default::Rotation3D;
+    use core::f32::consts::FRAC_PI_2;
+
+    let r = Rotation3D::around_z(Angle::radians(FRAC_PI_2));
+
+    assert!(r
+        .transform_point3d(point3(1.0, 0.0, 0.0))
+        .approx_eq(&point3(0.0, 1.0, 0.0)));
+    assert!(r
+        .transform_vector3d(vec3(1.0, 0.0, 0.0))
+        .approx_eq(&vec3(0.0, 1.0, 0.0)));
+
+    // Points are rotated around the origin, not translated.
+    assert!(r
+        .transform_point3d(point3(1.0, 2.0, 3.0))
+        .approx_eq(&point3(-2.0, 1.0, 3.0)));
+    assert!(r
+        .transform_vector3d(vec3(1.0, 2.0, 3.0))
+        .approx_eq(&vec3(-2.0, 1.0, 3.0)));
+}
+
 #[test]
 fn pre_post() {
     use crate::default::Rotation3D;
@@ -1049,3 +1104,36 @@ fn from_euler() {
 
     assert!(ypr_pe.approx_eq(&ypr_pq));
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_rotation_3d() {
+    use crate::default::Rotation3D;
+    use serde_test::{assert_tokens, Token};
+
+    // Not a unit quaternion: the components must round-trip without being renormalized.
+    let r = Rotation3D::quaternion(1.0f32, 2.0, 3.0, 4.0);
+
+    assert_tokens(
+        &r,
+        &[
+            Token::Struct {
+                name: "Rotation3D",
+                len: 5,
+            },
+            Token::Str("i"),
+            Token::F32(1.0),
+            Token::Str("j"),
+            Token::F32(2.0),
+            Token::Str("k"),
+            Token::F32(3.0),
+            Token::Str("r"),
+            Token::F32(4.0),
+            Token::Str("_unit"),
+            Token::UnitStruct {
+                name: "PhantomData",
+            },
+            Token::StructEnd,
+        ],
+    );
+}
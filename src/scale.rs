@@ -11,7 +11,7 @@
 use crate::num::One;
 
 use crate::approxord::{max, min};
-use crate::{Box2D, Box3D, Point2D, Point3D, Rect, Size2D, Vector2D};
+use crate::{Box2D, Box3D, Length, Point2D, Point3D, Rect, Size2D, Vector2D};
 
 use core::cmp::Ordering;
 use core::fmt;
@@ -70,6 +70,39 @@ impl<T, Src, Dst> Scale<T, Src, Dst> {
         Scale::new(T::one())
     }
 
+    /// Returns the given length transformed by this scale.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use euclid::{Scale, Length};
+    /// enum Mm {};
+    /// enum Cm {};
+    ///
+    /// let to_mm: Scale<i32, Cm, Mm> = Scale::new(10);
+    ///
+    /// assert_eq!(to_mm.transform_length(Length::new(42)), Length::new(420));
+    /// ```
+    #[inline]
+    pub fn transform_length(self, length: Length<T, Src>) -> Length<T::Output, Dst>
+    where
+        T: Copy + Mul,
+    {
+        Length::new(length.0 * self.0)
+    }
+
+    /// Returns an iterator over the given lengths transformed by this scale.
+    #[inline]
+    pub fn transform_lengths<I>(self, lengths: I) -> impl Iterator<Item = Length<T::Output, Dst>>
+    where
+        T: Copy + Mul,
+        I: IntoIterator<Item = Length<T, Src>>,
+    {
+        lengths
+            .into_iter()
+            .map(move |length| self.transform_length(length))
+    }
+
     /// Returns the given point transformed by this scale.
     ///
     /// # Example
@@ -430,6 +463,7 @@ impl<T: One, Src, Dst> One for Scale<T, Src, Dst> {
 #[cfg(test)]
 mod tests {
     use super::Scale;
+    use crate::{point2, rect, Point2D, Rect};
 
     enum Inch {}
     enum Cm {}
@@ -470,4 +504,54 @@ mod tests {
         let c = Scale::<f32, Inch, Inch>::new(2.5);
         assert_eq!(c.clamp(a, b), c);
     }
+
+    #[test]
+    fn test_hash_map_key() {
+        use std::collections::HashMap;
+
+        let mut cache: HashMap<Scale<i32, Inch, Mm>, &str> = HashMap::new();
+        cache.insert(Scale::new(1), "one");
+        cache.insert(Scale::new(2), "two");
+        cache.insert(Scale::new(2), "deux");
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache[&Scale::new(2)], "deux");
+        assert!(!cache.contains_key(&Scale::new(3)));
+    }
+
+    #[test]
+    fn test_logical_to_device() {
+        enum Logical {}
+        enum Device {}
+
+        let dpi: Scale<f32, Logical, Device> = Scale::new(2.0);
+
+        let p: Point2D<f32, Logical> = point2(10.0, 15.5);
+        let device_p: Point2D<f32, Device> = dpi.transform_point(p);
+        assert_eq!(device_p, point2(20.0, 31.0));
+        assert_eq!(device_p, p * dpi);
+
+        let r: Rect<f32, Logical> = rect(1.0, 2.0, 30.0, 40.0);
+        let device_r: Rect<f32, Device> = dpi.transform_rect(&r);
+        assert_eq!(device_r, rect(2.0, 4.0, 60.0, 80.0));
+        assert_eq!(device_r, r * dpi);
+    }
+
+    #[test]
+    fn test_transform_length() {
+        use crate::Length;
+
+        let mm_per_inch: Scale<f32, Inch, Mm> = Scale::new(25.4);
+        let one_inch: Length<f32, Inch> = Length::new(1.0);
+        let mm: Length<f32, Mm> = mm_per_inch.transform_length(one_inch);
+        assert_eq!(mm, Length::new(25.4));
+        assert_eq!(mm, one_inch * mm_per_inch);
+
+        let inches = [Length::new(1.0), Length::new(2.0), Length::new(10.0)];
+        let mms: Vec<Length<f32, Mm>> = mm_per_inch.transform_lengths(inches).collect();
+        assert_eq!(
+            mms,
+            [Length::new(25.4), Length::new(50.8), Length::new(254.0)]
+        );
+    }
 }
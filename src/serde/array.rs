@@ -0,0 +1,93 @@
+// Copyright 2013 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Serializes a [`Rect`] as a flat `[x, y, width, height]` sequence instead of
+//! the default structure with an origin and a size, to be used with serde's `with`
+//! field attribute.
+//!
+//! ```rust
+//! use euclid::default::Rect;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Layer {
+//!     #[serde(with = "euclid::serde::array")]
+//!     bounds: Rect<f32>,
+//! }
+//! ```
+//!
+//! [`Rect`]: crate::Rect
+
+use crate::{point2, size2, Rect};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes `rect` as a `[x, y, width, height]` sequence.
+pub fn serialize<T, U, S>(rect: &Rect<T, U>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    (
+        &rect.origin.x,
+        &rect.origin.y,
+        &rect.size.width,
+        &rect.size.height,
+    )
+        .serialize(serializer)
+}
+
+/// Deserializes a rectangle from a `[x, y, width, height]` sequence.
+pub fn deserialize<'de, T, U, D>(deserializer: D) -> Result<Rect<T, U>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let (x, y, width, height) = Deserialize::deserialize(deserializer)?;
+    Ok(Rect::new(point2(x, y), size2(width, height)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::default::Rect;
+    use crate::rect;
+
+    use ::serde::{Deserialize, Serialize};
+    use serde_test::{assert_tokens, Token};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Layer {
+        #[serde(with = "crate::serde::array")]
+        bounds: Rect<i32>,
+    }
+
+    #[test]
+    fn test_rect_array() {
+        let layer = Layer {
+            bounds: rect(1, 2, 3, 4),
+        };
+
+        assert_tokens(
+            &layer,
+            &[
+                Token::Struct {
+                    name: "Layer",
+                    len: 1,
+                },
+                Token::Str("bounds"),
+                Token::Tuple { len: 4 },
+                Token::I32(1),
+                Token::I32(2),
+                Token::I32(3),
+                Token::I32(4),
+                Token::TupleEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -13,7 +13,7 @@
 use crate::length::Length;
 use crate::num::Zero;
 use crate::scale::Scale;
-use crate::Vector2D;
+use crate::{UnknownUnit, Vector2D};
 
 use core::cmp::{Eq, PartialEq};
 use core::fmt;
@@ -225,6 +225,33 @@ impl<T, U> SideOffsets2D<T, U> {
         SideOffsets2D::new_all_same(all.0)
     }
 
+    /// Cast the unit.
+    #[inline]
+    pub fn cast_unit<V>(&self) -> SideOffsets2D<T, V>
+    where
+        T: Copy,
+    {
+        SideOffsets2D::new(self.top, self.right, self.bottom, self.left)
+    }
+
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> SideOffsets2D<T, UnknownUnit>
+    where
+        T: Copy,
+    {
+        self.cast_unit()
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(s: &SideOffsets2D<T, UnknownUnit>) -> Self
+    where
+        T: Copy,
+    {
+        s.cast_unit()
+    }
+
     pub fn horizontal(&self) -> T
     where
         T: Copy + Add<T, Output = T>,
@@ -448,6 +475,18 @@ mod ops {
     type SideOffsets2DMm<T> = crate::SideOffsets2D<T, Mm>;
     type SideOffsets2DCm<T> = crate::SideOffsets2D<T, Cm>;
 
+    #[test]
+    fn test_untyped() {
+        let s = SideOffsets2DMm::new(1.0, 2.0, 3.0, 4.0);
+        let u: SideOffsets2D<f32> = s.to_untyped();
+        assert_eq!(u, SideOffsets2D::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(SideOffsets2DMm::from_untyped(&u), s);
+        assert_eq!(
+            s.cast_unit::<Cm>(),
+            SideOffsets2DCm::new(1.0, 2.0, 3.0, 4.0)
+        );
+    }
+
     #[test]
     fn test_mul_scalar() {
         let s = SideOffsets2D::new(1.0, 2.0, 3.0, 4.0);
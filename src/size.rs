@@ -7,11 +7,12 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use super::UnknownUnit;
+use super::{DisplayWithUnits, Unit, UnknownUnit};
 use crate::approxord::{max, min};
 use crate::length::Length;
 use crate::num::*;
 use crate::scale::Scale;
+use crate::side_offsets::SideOffsets2D;
 use crate::vector::{vec2, BoolVector2D, Vector2D};
 use crate::vector::{vec3, BoolVector3D, Vector3D};
 
@@ -128,6 +129,8 @@ where
     }
 }
 
+approx_impls!(Size2D<U> { width, height });
+
 impl<T: fmt::Debug, U> fmt::Debug for Size2D<T, U> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         fmt::Debug::fmt(&self.width, f)?;
@@ -136,6 +139,24 @@ impl<T: fmt::Debug, U> fmt::Debug for Size2D<T, U> {
     }
 }
 
+impl<T: fmt::Display, U> fmt::Display for Size2D<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.width, f)?;
+        write!(f, "x")?;
+        fmt::Display::fmt(&self.height, f)
+    }
+}
+
+impl<T: fmt::Display, U: Unit> fmt::Display for DisplayWithUnits<'_, Size2D<T, U>> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.0, f)?;
+        if !U::NAME.is_empty() {
+            write!(f, " {}", U::NAME)?;
+        }
+        Ok(())
+    }
+}
+
 impl<T: Default, U> Default for Size2D<T, U> {
     fn default() -> Self {
         Size2D::new(Default::default(), Default::default())
@@ -169,6 +190,16 @@ impl<T, U> Size2D<T, U> {
         Size2D::new(width.0, height.0)
     }
 
+    /// Returns an object that displays this size followed by the name of its unit,
+    /// for example `30x40 px`.
+    #[inline]
+    pub fn display_with_units(&self) -> DisplayWithUnits<'_, Self>
+    where
+        U: Unit,
+    {
+        DisplayWithUnits(self)
+    }
+
     /// Constructor setting all components to the same value.
     #[inline]
     pub fn splat(v: T) -> Self
@@ -202,6 +233,20 @@ impl<T: Copy, U> Size2D<T, U> {
         (self.width, self.height)
     }
 
+    /// Returns an iterator over the components of this size, width then height.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = T> {
+        self.to_array().into_iter()
+    }
+
+    /// Swaps the width and height of this size.
+    ///
+    /// Useful when rotating content by 90 degrees.
+    #[inline]
+    pub fn transpose(self) -> Self {
+        size2(self.height, self.width)
+    }
+
     /// Return this size as a vector with width and height.
     #[inline]
     pub fn to_vector(self) -> Vector2D<T, U> {
@@ -312,6 +357,28 @@ impl<T: Copy, U> Size2D<T, U> {
     }
 }
 
+impl<T: Copy + Add<T, Output = T>, U> Size2D<T, U> {
+    /// Grows the size by `width` on the left and right and by `height` on the
+    /// top and bottom, as [`Rect::inflate`] would.
+    ///
+    /// [`Rect::inflate`]: crate::Rect::inflate
+    #[inline]
+    #[must_use]
+    pub fn inflate(self, width: T, height: T) -> Self {
+        size2(self.width + width + width, self.height + height + height)
+    }
+
+    /// Grows the size by the horizontal and vertical totals of the given offsets.
+    #[inline]
+    #[must_use]
+    pub fn add_offsets(self, offsets: &SideOffsets2D<T, U>) -> Self {
+        size2(
+            self.width + offsets.horizontal(),
+            self.height + offsets.vertical(),
+        )
+    }
+}
+
 impl<T: NumCast + Copy, U> Size2D<T, U> {
     /// Cast from one numeric representation to another, preserving the units.
     ///
@@ -449,6 +516,19 @@ impl<T: PartialOrd, U> Size2D<T, U> {
         self.max(start).min(end)
     }
 
+    /// Returns the size with negative components replaced by zero.
+    ///
+    /// `NaN` components are replaced by zero as well.
+    ///
+    /// Shortcut for `self.max(Size2D::zero())`.
+    #[inline]
+    pub fn clamp_to_positive(self) -> Self
+    where
+        T: Zero,
+    {
+        self.max(Size2D::zero())
+    }
+
     // Returns true if this size is larger or equal to the other size in all dimensions.
     #[inline]
     pub fn contains(self, other: Self) -> bool {
@@ -723,9 +803,16 @@ impl<T, U> From<(T, T)> for Size2D<T, U> {
     }
 }
 
+impl<T, U> From<(Length<T, U>, Length<T, U>)> for Size2D<T, U> {
+    #[inline]
+    fn from((width, height): (Length<T, U>, Length<T, U>)) -> Self {
+        Size2D::from_lengths(width, height)
+    }
+}
+
 #[cfg(test)]
 mod size2d {
-    use crate::default::Size2D;
+    use crate::default::{Length, Size2D};
     #[cfg(feature = "mint")]
     use mint;
 
@@ -735,6 +822,80 @@ mod size2d {
         assert_eq!(p.area(), 3.0);
     }
 
+    #[test]
+    pub fn test_transpose() {
+        use crate::size2;
+
+        let s: Size2D<i32> = size2(3, 4);
+        assert_eq!(s.transpose(), size2(4, 3));
+        assert_eq!(s.transpose().transpose(), s);
+    }
+
+    #[test]
+    pub fn test_inflate() {
+        use crate::default::SideOffsets2D;
+
+        let s = Size2D::new(10, 10);
+        assert_eq!(s.inflate(2, 3), Size2D::new(14, 16));
+        assert_eq!(
+            s.add_offsets(&SideOffsets2D::new(1, 2, 3, 4)),
+            Size2D::new(16, 14)
+        );
+    }
+
+    #[test]
+    pub fn test_clamp_to_positive() {
+        assert_eq!(
+            Size2D::new(-3.0, 5.0).clamp_to_positive(),
+            Size2D::new(0.0, 5.0)
+        );
+        assert_eq!(Size2D::new(3, -5).clamp_to_positive(), Size2D::new(3, 0));
+        assert_eq!(Size2D::new(-3, -5).clamp_to_positive(), Size2D::zero());
+        assert_eq!(
+            Size2D::new(f32::NAN, 1.0).clamp_to_positive(),
+            Size2D::new(0.0, 1.0)
+        );
+    }
+
+    #[test]
+    pub fn test_cast() {
+        let s = Size2D::new(-1.5, 2.0);
+        assert_eq!(s.try_cast::<usize>(), None);
+        assert_eq!(s.cast::<i32>(), Size2D::new(-1, 2));
+        assert_eq!(
+            Size2D::new(1.5, 2.0).try_cast(),
+            Some(Size2D::new(1usize, 2))
+        );
+    }
+
+    #[test]
+    pub fn test_display() {
+        struct Px;
+        impl crate::Unit for Px {
+            const NAME: &'static str = "px";
+        }
+
+        let s: crate::Size2D<i32, Px> = crate::size2(30, 40);
+        assert_eq!(format!("{}", s), "30x40");
+        assert_eq!(format!("{}", s.display_with_units()), "30x40 px");
+
+        // Units don't need to implement `Unit` to be displayed.
+        struct Mm;
+        let s: crate::Size2D<i32, Mm> = crate::size2(3, 4);
+        assert_eq!(format!("{}", s), "3x4");
+        assert_eq!(format!("{}", Size2D::new(1.5, 2.0)), "1.5x2");
+        assert_eq!(
+            format!("{}", Size2D::new(1.5, 2.0).display_with_units()),
+            "1.5x2"
+        );
+    }
+
+    #[test]
+    pub fn test_from_lengths_tuple() {
+        let s: Size2D<f32> = (Length::new(1.0), Length::new(2.0)).into();
+        assert_eq!(s, Size2D::new(1.0, 2.0));
+    }
+
     #[cfg(feature = "mint")]
     #[test]
     pub fn test_mint() {
@@ -762,6 +923,11 @@ mod size2d {
             assert_eq!(-Size2D::new(-1.0, -2.0), Size2D::new(1.0, 2.0));
         }
 
+        #[test]
+        pub fn test_neg_integer() {
+            assert_eq!(-Size2D::new(2, -3), Size2D::new(-2, 3));
+        }
+
         #[test]
         pub fn test_add() {
             let s1 = Size2D::new(1.0, 2.0);
@@ -1041,6 +1207,8 @@ where
     }
 }
 
+approx_impls!(Size3D<U> { width, height, depth });
+
 impl<T: fmt::Debug, U> fmt::Debug for Size3D<T, U> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         fmt::Debug::fmt(&self.width, f)?;
@@ -1118,6 +1286,13 @@ impl<T: Copy, U> Size3D<T, U> {
         (self.width, self.height, self.depth)
     }
 
+    /// Rotates the axes of this size, so that the width becomes the depth, the
+    /// height becomes the width and the depth becomes the height.
+    #[inline]
+    pub fn rotate_axes(self) -> Self {
+        size3(self.height, self.depth, self.width)
+    }
+
     /// Return this size as a vector with width, height and depth.
     #[inline]
     pub fn to_vector(self) -> Vector3D<T, U> {
@@ -1676,6 +1851,58 @@ pub const fn size3<T, U>(w: T, h: T, d: T) -> Size3D<T, U> {
 
 #[cfg(test)]
 mod size3d {
+    use crate::default::{Length, Size3D};
+
+    #[test]
+    pub fn test_rotate_axes() {
+        let s = Size3D::new(1, 2, 3);
+        assert_eq!(s.rotate_axes(), Size3D::new(2, 3, 1));
+        assert_eq!(s.rotate_axes().rotate_axes().rotate_axes(), s);
+    }
+
+    #[test]
+    pub fn test_volume() {
+        let s = Size3D::new(1.5, 2.0, 3.0);
+        assert_eq!(s.volume(), 9.0);
+    }
+
+    #[test]
+    pub fn test_from_lengths() {
+        let s = Size3D::from_lengths(Length::new(1.0), Length::new(2.0), Length::new(3.0));
+        assert_eq!(s, Size3D::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    pub fn test_cast() {
+        let s = Size3D::new(1.5, -2.7, 3.0);
+        assert_eq!(s.cast::<i32>(), Size3D::new(1, -2, 3));
+        assert_eq!(s.try_cast::<u32>(), None);
+        assert_eq!(Size3D::new(1, 2, 3).to_f32(), Size3D::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    pub fn test_untyped() {
+        enum Mm {}
+        let s: crate::Size3D<f32, Mm> = crate::Size3D::new(1.0, 2.0, 3.0);
+        assert_eq!(s.to_untyped(), Size3D::new(1.0, 2.0, 3.0));
+        assert_eq!(crate::Size3D::from_untyped(s.to_untyped()), s);
+    }
+
+    #[test]
+    pub fn test_debug() {
+        assert_eq!(format!("{:?}", Size3D::new(1, 2, 3)), "1x2x3");
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    pub fn test_mint() {
+        let s1 = Size3D::new(1.0, 2.0, 3.0);
+        let sm: mint::Vector3<_> = s1.into();
+        let s2 = Size3D::from(sm);
+
+        assert_eq!(s1, s2);
+    }
+
     mod ops {
         use crate::default::{Size2D, Size3D};
         use crate::scale::Scale;
@@ -1850,6 +2077,17 @@ mod size3d {
             assert_eq!(result, Size3DMm::new(1.0, 2.0, 3.0));
         }
 
+        #[test]
+        pub fn test_scale_round_trip() {
+            let s1 = Size3DMm::new(10, 20, 30);
+            let mm_per_cm: Scale<i32, Cm, Mm> = Scale::new(10);
+
+            let s2: Size3DMm<i32> = (s1 / mm_per_cm) * mm_per_cm;
+
+            assert_eq!(s1 / mm_per_cm, Size3DCm::new(1, 2, 3));
+            assert_eq!(s2, s1);
+        }
+
         #[test]
         pub fn test_div_assign_scale() {
             let mut s1 = Size3DMm::new(0.1, 0.2, 0.3);
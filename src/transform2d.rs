@@ -9,12 +9,14 @@
 
 #![allow(clippy::just_underscores_and_digits)]
 
-use super::{Angle, UnknownUnit};
+use super::{Angle, DisplayWithUnits, Unit, UnknownUnit};
 use crate::approxeq::ApproxEq;
 use crate::box2d::Box2D;
 use crate::num::{One, Zero};
 use crate::point::{point2, Point2D};
 use crate::rect::Rect;
+use crate::rotation::Rotation2D;
+use crate::size::{size2, Size2D};
 use crate::transform3d::Transform3D;
 use crate::trig::Trig;
 use crate::vector::{vec2, Vector2D};
@@ -28,7 +30,7 @@ use core::ops::{Add, Div, Mul, Sub};
 use bytemuck::{Pod, Zeroable};
 #[cfg(feature = "mint")]
 use mint;
-use num_traits::NumCast;
+use num_traits::{Float, NumCast};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -144,6 +146,8 @@ where
     }
 }
 
+approx_impls!(Transform2D<Src, Dst> { m11, m12, m21, m22, m31, m32 });
+
 impl<T, Src, Dst> Transform2D<T, Src, Dst> {
     /// Create a transform specifying its components in using the column-major-column-vector
     /// matrix notation.
@@ -174,6 +178,9 @@ impl<T, Src, Dst> Transform2D<T, Src, Dst> {
     /// `T`'s default epsilon value.
     ///
     /// The same as [`ApproxEq::approx_eq`] but available without importing trait.
+    ///
+    /// Components that are NaN in both transforms are not considered equal, see
+    /// [`approx_eq_nan_equal`](Self::approx_eq_nan_equal).
     #[inline]
     pub fn approx_eq(&self, other: &Self) -> bool
     where
@@ -193,6 +200,55 @@ impl<T, Src, Dst> Transform2D<T, Src, Dst> {
     {
         <Self as ApproxEq<T>>::approx_eq_eps(self, other, eps)
     }
+
+    /// Returns `true` if this transform is approximately equal to the other one, using
+    /// `T`'s default epsilon value, and treating components that are NaN in both
+    /// transforms as equal.
+    ///
+    /// This is useful when comparing the results of operations on singular transforms.
+    #[inline]
+    pub fn approx_eq_nan_equal(&self, other: &Self) -> bool
+    where
+        T: ApproxEq<T> + Float,
+    {
+        self.to_array()
+            .iter()
+            .zip(other.to_array().iter())
+            .all(|(a, b)| (a.is_nan() && b.is_nan()) || a.approx_eq(b))
+    }
+
+    /// Returns an object that displays this transform followed by the names of the
+    /// source and destination units, unless both are empty.
+    ///
+    /// ```rust
+    /// use euclid::{Transform2D, Unit};
+    ///
+    /// struct WorldSpace;
+    /// impl Unit for WorldSpace {
+    ///     const NAME: &'static str = "world";
+    /// }
+    ///
+    /// struct ScreenSpace;
+    /// impl Unit for ScreenSpace {
+    ///     const NAME: &'static str = "screen";
+    /// }
+    ///
+    /// let t: Transform2D<f32, WorldSpace, ScreenSpace> = Transform2D::translation(5.0, 6.0);
+    ///
+    /// assert_eq!(t.to_string(), "[1, 0; 0, 1; 5, 6]");
+    /// assert_eq!(
+    ///     t.display_with_units().to_string(),
+    ///     "[1, 0; 0, 1; 5, 6] (world -> screen)"
+    /// );
+    /// ```
+    #[inline]
+    pub fn display_with_units(&self) -> DisplayWithUnits<'_, Self>
+    where
+        Src: Unit,
+        Dst: Unit,
+    {
+        DisplayWithUnits(self)
+    }
 }
 
 impl<T: Copy, Src, Dst> Transform2D<T, Src, Dst> {
@@ -296,6 +352,17 @@ impl<T: Copy, Src, Dst> Transform2D<T, Src, Dst> {
         )
     }
 
+    /// Cast the unit.
+    #[inline]
+    #[rustfmt::skip]
+    pub fn cast_unit<Src2, Dst2>(&self) -> Transform2D<T, Src2, Dst2> {
+        Transform2D::new(
+            self.m11, self.m12,
+            self.m21, self.m22,
+            self.m31, self.m32
+        )
+    }
+
     /// Returns the same transform with a different source unit.
     #[inline]
     #[rustfmt::skip]
@@ -354,6 +421,27 @@ impl<T: NumCast + Copy, Src, Dst> Transform2D<T, Src, Dst> {
     }
 }
 
+impl<T: Zero, Src, Dst> Transform2D<T, Src, Dst> {
+    /// Create a matrix with all components set to zero:
+    ///
+    /// ```text
+    /// 0 0
+    /// 0 0
+    /// 0 0
+    /// ```
+    #[inline]
+    pub fn zero() -> Self {
+        Self::new(
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+        )
+    }
+}
+
 impl<T, Src, Dst> Transform2D<T, Src, Dst>
 where
     T: Zero + One,
@@ -404,6 +492,22 @@ where
     }
 }
 
+impl<T, U> Transform2D<T, U, U>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Zero + One,
+{
+    /// Returns the combination of all transforms in the slice, in order.
+    ///
+    /// The first transform of the slice is applied first, so `compose_all(&[a, b, c])`
+    /// is equivalent to `a.then(&b).then(&c)`. An empty slice produces the identity.
+    #[must_use]
+    pub fn compose_all(transforms: &[Self]) -> Self {
+        transforms
+            .iter()
+            .fold(Self::identity(), |acc, transform| acc.then(transform))
+    }
+}
+
 /// Methods for creating and combining translation transformations
 impl<T, Src, Dst> Transform2D<T, Src, Dst>
 where
@@ -482,6 +586,20 @@ where
     pub fn pre_rotate(&self, theta: Angle<T>) -> Self {
         Transform2D::rotation(theta).then(self)
     }
+
+    /// Applies a `Rotation2D` after self's transformation and returns the resulting transform.
+    #[inline]
+    #[must_use]
+    pub fn then_rotation(&self, r: &Rotation2D<T, Dst, Dst>) -> Self {
+        self.then_rotate(r.get_angle())
+    }
+
+    /// Applies a `Rotation2D` before self's transformation and returns the resulting transform.
+    #[inline]
+    #[must_use]
+    pub fn pre_rotation(&self, r: &Rotation2D<T, Src, Src>) -> Self {
+        self.pre_rotate(r.get_angle())
+    }
 }
 
 /// Methods for creating and combining scale transformations
@@ -559,6 +677,26 @@ where
         )
     }
 
+    /// Returns the given size transformed by this transform.
+    ///
+    /// Only the scale components (`m11` and `m22`) are applied, so this is only
+    /// meaningful for transforms without rotation or skew. Use
+    /// [`outer_transformed_rect`](Self::outer_transformed_rect) for the general case.
+    #[inline]
+    #[must_use]
+    pub fn transform_size(&self, size: Size2D<T, Src>) -> Size2D<T, Dst> {
+        size2(size.width * self.m11, size.height * self.m22)
+    }
+
+    /// Returns the four given points transformed by this transform.
+    ///
+    /// Convenient for transforming the corners of an arbitrary quad.
+    #[inline]
+    #[must_use]
+    pub fn transform_points_4(&self, points: [Point2D<T, Src>; 4]) -> [Point2D<T, Dst>; 4] {
+        points.map(|p| self.transform_point(p))
+    }
+
     /// Returns a rectangle that encompasses the result of transforming the given rectangle by this
     /// transform.
     #[inline]
@@ -577,6 +715,84 @@ where
         ])
     }
 
+    /// Computes [`outer_transformed_rect`] for each rectangle of `input`, writing
+    /// the results into `output`.
+    ///
+    /// Each transformed coordinate is a sum of terms that only depend on one input
+    /// coordinate, so instead of transforming the four corners of each rectangle,
+    /// the signs of the matrix components are inspected once to select which bound
+    /// of each rectangle produces the smallest and largest transformed coordinates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` and `output` have different lengths.
+    ///
+    /// [`outer_transformed_rect`]: Self::outer_transformed_rect
+    pub fn outer_transformed_rects(&self, input: &[Rect<T, Src>], output: &mut [Rect<T, Dst>])
+    where
+        T: Sub<Output = T> + Zero + PartialOrd,
+    {
+        assert_eq!(input.len(), output.len());
+
+        let signs = self.component_signs();
+        for (rect, out) in input.iter().zip(output.iter_mut()) {
+            *out = self.outer_transformed_rect_with_signs(signs, rect);
+        }
+    }
+
+    /// Returns whether `m11`, `m21`, `m12` and `m22` are positive, in that order.
+    #[inline]
+    pub(crate) fn component_signs(&self) -> [bool; 4]
+    where
+        T: Zero + PartialOrd,
+    {
+        let _0 = T::zero();
+        [
+            self.m11 >= _0,
+            self.m21 >= _0,
+            self.m12 >= _0,
+            self.m22 >= _0,
+        ]
+    }
+
+    /// Computes [`outer_transformed_rect`] using the signs returned by
+    /// [`component_signs`] to select the bounds of `rect` that produce the smallest
+    /// and largest transformed coordinates.
+    ///
+    /// [`outer_transformed_rect`]: Self::outer_transformed_rect
+    /// [`component_signs`]: Self::component_signs
+    #[inline]
+    pub(crate) fn outer_transformed_rect_with_signs(
+        &self,
+        signs: [bool; 4],
+        rect: &Rect<T, Src>,
+    ) -> Rect<T, Dst>
+    where
+        T: Sub<Output = T> + PartialOrd,
+    {
+        let [x_from_x, x_from_y, y_from_x, y_from_y] = signs;
+        // Returns the (lower, upper) bounds in the order that minimizes the first
+        // term and maximizes the second one.
+        let order = |positive: bool, (lo, hi): (T, T)| if positive { (lo, hi) } else { (hi, lo) };
+        let sort = |a: T, b: T| if a <= b { (a, b) } else { (b, a) };
+
+        let (min, max) = (rect.min(), rect.max());
+        let xs = sort(min.x, max.x);
+        let ys = sort(min.y, max.y);
+
+        let (x_lo, x_hi) = order(x_from_x, xs);
+        let (y_lo, y_hi) = order(x_from_y, ys);
+        let min_x = x_lo * self.m11 + y_lo * self.m21 + self.m31;
+        let max_x = x_hi * self.m11 + y_hi * self.m21 + self.m31;
+
+        let (x_lo, x_hi) = order(y_from_x, xs);
+        let (y_lo, y_hi) = order(y_from_y, ys);
+        let min_y = x_lo * self.m12 + y_lo * self.m22 + self.m32;
+        let max_y = x_hi * self.m12 + y_hi * self.m22 + self.m32;
+
+        Rect::new(point2(min_x, min_y), size2(max_x - min_x, max_y - min_y))
+    }
+
     /// Returns a box that encompasses the result of transforming the given box by this
     /// transform.
     #[inline]
@@ -609,6 +825,36 @@ where
         self.determinant() != Zero::zero()
     }
 
+    /// Returns whether the determinant of this transform is within `T`'s default
+    /// epsilon value of zero.
+    ///
+    /// Unlike [`is_invertible`], this also catches transforms with a tiny but non-zero
+    /// determinant. The epsilon is absolute and does not take the scale of the
+    /// transform into account: a small uniform scale is considered singular even
+    /// though it is well-conditioned, while an ill-conditioned transform with a large
+    /// determinant isn't.
+    ///
+    /// [`is_invertible`]: Self::is_invertible
+    #[inline]
+    pub fn is_singular(&self) -> bool
+    where
+        T: ApproxEq<T>,
+    {
+        self.determinant().approx_eq(&T::zero())
+    }
+
+    /// Returns whether this transform flips orientation, i.e. whether its
+    /// determinant is negative (as with a mirror).
+    ///
+    /// A flipping transform reverses the winding order of the shapes it is applied to.
+    #[inline]
+    pub fn flips_orientation(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.determinant() < Zero::zero()
+    }
+
     /// Returns the inverse transform if possible.
     #[must_use]
     pub fn inverse(&self) -> Option<Transform2D<T, Dst, Src>> {
@@ -674,6 +920,34 @@ where
     }
 }
 
+/// Formats the matrix one row at a time.
+///
+/// Use [`display_with_units`] to also display the names of the source and
+/// destination units.
+///
+/// [`display_with_units`]: Transform2D::display_with_units
+impl<T: fmt::Display, Src, Dst> fmt::Display for Transform2D<T, Src, Dst> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[{}, {}; {}, {}; {}, {}]",
+            self.m11, self.m12, self.m21, self.m22, self.m31, self.m32,
+        )
+    }
+}
+
+impl<T: fmt::Display, Src: Unit, Dst: Unit> fmt::Display
+    for DisplayWithUnits<'_, Transform2D<T, Src, Dst>>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.0, f)?;
+        if !Src::NAME.is_empty() || !Dst::NAME.is_empty() {
+            write!(f, " ({} -> {})", Src::NAME, Dst::NAME)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(feature = "mint")]
 impl<T, Src, Dst> From<mint::RowMatrix3x2<T>> for Transform2D<T, Src, Dst> {
     #[rustfmt::skip]
@@ -744,6 +1018,135 @@ mod test {
         assert!(r1.then(&r1).approx_eq(&Mat::rotation(rad(FRAC_PI_2 * 2.0))));
     }
 
+    #[test]
+    pub fn test_rotation2d() {
+        let m = Mat::translation(1.0, 2.0);
+        let r = default::Rotation2D::new(rad(FRAC_PI_2));
+
+        assert!(m
+            .then_rotation(&r)
+            .approx_eq(&m.then_rotate(rad(FRAC_PI_2))));
+        assert!(m.pre_rotation(&r).approx_eq(&m.pre_rotate(rad(FRAC_PI_2))));
+        assert!(m
+            .then_rotation(&r)
+            .approx_eq(&m.then(&Mat::rotation(rad(FRAC_PI_2)))));
+    }
+
+    #[test]
+    pub fn test_approx_eq_eps() {
+        let m1: Mat = Mat::translation(1.0, 2.0).then_rotate(rad(0.5));
+        let mut m2 = m1;
+        m2.m11 += 1e-4;
+
+        assert!(!m1.approx_eq(&m2));
+        assert!(m1.approx_eq_eps(&m2, &1e-3));
+        assert!(!m1.approx_eq_eps(&m2, &1e-5));
+    }
+
+    #[test]
+    pub fn test_is_singular() {
+        assert!(Mat::zero().is_singular());
+        assert!(Mat::scale(0.0, 2.0).is_singular());
+        assert!(!Mat::identity().is_singular());
+        assert!(!Mat::rotation(rad(0.5)).is_singular());
+        // The epsilon is absolute, so small uniform scales are singular too.
+        assert!(Mat::scale(0.0001, 0.0001).is_singular());
+        assert!(Mat::scale(0.0001, 0.0001).is_invertible());
+        assert_eq!(Mat::zero().to_array(), [0.0; 6]);
+    }
+
+    #[test]
+    pub fn test_display() {
+        assert_eq!(Mat::identity().to_string(), "[1, 0; 0, 1; 0, 0]");
+        assert_eq!(Mat::scale(2.0, 3.5).to_string(), "[2, 0; 0, 3.5; 0, 0]");
+        assert_eq!(
+            Mat::scale(2.0, 3.5).display_with_units().to_string(),
+            "[2, 0; 0, 3.5; 0, 0]"
+        );
+
+        // Units don't need to implement `Unit` to be displayed.
+        struct Mm;
+        let t: Transform2D<f32, Mm, Mm> = Transform2D::identity();
+        assert_eq!(t.to_string(), "[1, 0; 0, 1; 0, 0]");
+    }
+
+    #[test]
+    pub fn test_flips_orientation() {
+        assert!(Mat::scale(-1.0, 1.0).flips_orientation());
+        assert!(Mat::scale(1.0, -2.0).flips_orientation());
+        assert!(!Mat::scale(-1.0, -1.0).flips_orientation());
+        assert!(!Mat::identity().flips_orientation());
+        assert!(!Mat::rotation(rad(2.0)).flips_orientation());
+    }
+
+    #[test]
+    pub fn test_approx_eq_nan_equal() {
+        let mut m1 = Mat::identity();
+        m1.m11 = f32::NAN;
+        let mut m2 = m1;
+        m2.m12 += 1e-7;
+
+        assert!(!m1.approx_eq(&m2));
+        assert!(m1.approx_eq_nan_equal(&m2));
+
+        m2.m11 = 1.0;
+        assert!(!m1.approx_eq_nan_equal(&m2));
+    }
+
+    #[test]
+    pub fn test_outer_transformed_rects() {
+        use crate::rect;
+
+        let input = [
+            rect(0.0, 0.0, 10.0, 10.0),
+            rect(-5.0, 3.0, 1.0, 20.0),
+            rect(2.0, 2.0, 0.0, 0.0),
+        ];
+        for m in [
+            Mat::rotation(rad(0.5)).then_translate(vec2(1.0, 2.0)),
+            Mat::rotation(rad(2.5)).then_scale(-1.0, 2.0),
+            Mat::scale(1.0, -3.0),
+        ] {
+            let mut output = [Rect::zero(); 3];
+            m.outer_transformed_rects(&input, &mut output);
+            for (r, out) in input.iter().zip(output.iter()) {
+                assert_eq!(*out, m.outer_transformed_rect(r));
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_untyped() {
+        enum A {}
+        enum B {}
+
+        let m: Transform2D<f32, A, B> = Transform2D::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+        let u: Mat = m.to_untyped();
+        assert_eq!(u.to_array(), m.to_array());
+        assert_eq!(Transform2D::<f32, A, B>::from_untyped(&u), m);
+        assert_eq!(m.cast_unit::<B, A>().to_array(), m.to_array());
+    }
+
+    #[test]
+    pub fn test_transform_points_4() {
+        let r = Mat::rotation(rad(FRAC_PI_2));
+        let square = [
+            point2(0.0, 0.0),
+            point2(1.0, 0.0),
+            point2(1.0, 1.0),
+            point2(0.0, 1.0),
+        ];
+        let expected = [
+            point2(0.0, 0.0),
+            point2(0.0, 1.0),
+            point2(-1.0, 1.0),
+            point2(-1.0, 0.0),
+        ];
+        for (p, e) in r.transform_points_4(square).iter().zip(expected.iter()) {
+            assert!(p.approx_eq(e));
+        }
+    }
+
     #[test]
     pub fn test_scale() {
         let s1 = Mat::scale(2.0, 3.0);
@@ -822,6 +1225,19 @@ mod test {
             .approx_eq(&r.transform_point(t.transform_point(a))));
     }
 
+    #[test]
+    pub fn test_compose_all() {
+        let t = Mat::translation(1.0, 2.0);
+        let s = Mat::scale(2.0, 3.0);
+        let r = Mat::rotation(rad(FRAC_PI_2));
+
+        assert_eq!(Mat::compose_all(&[]), Mat::identity());
+        assert_eq!(Mat::compose_all(&[t]), t);
+        assert_eq!(Mat::compose_all(&[t, s]), t.then(&s));
+        assert_eq!(Mat::compose_all(&[s, t]), s.then(&t));
+        assert_eq!(Mat::compose_all(&[t, s, r]), t.then(&s).then(&r));
+    }
+
     #[test]
     fn test_size_of() {
         use core::mem::size_of;
@@ -837,6 +1253,16 @@ mod test {
         assert!(!m2.is_identity());
     }
 
+    #[test]
+    pub fn test_transform_size() {
+        let m = Mat::scale(2.0, 3.0);
+        assert_eq!(m.transform_size(size2(10.0, 10.0)), size2(20.0, 30.0));
+
+        // Translation does not affect sizes.
+        let m = m.then_translate(vec2(5.0, 5.0));
+        assert_eq!(m.transform_size(size2(10.0, 10.0)), size2(20.0, 30.0));
+    }
+
     #[test]
     pub fn test_transform_vector() {
         // Translation does not apply to vectors.
@@ -845,6 +1271,19 @@ mod test {
         assert_eq!(v1, m1.transform_vector(v1));
     }
 
+    #[test]
+    pub fn test_transform_vector_linear_part() {
+        // Unlike vectors, points are translated.
+        let m1 = Mat::translation(1.0, 1.0);
+        let v1 = vec2(10.0, -10.0);
+        let p1 = point2(10.0, -10.0);
+        assert_eq!(m1.transform_point(p1), point2(11.0, -9.0));
+
+        // The linear part still applies to vectors.
+        let m2 = Mat::scale(2.0, 3.0).then_translate(vec2(5.0, 5.0));
+        assert_eq!(m2.transform_vector(v1), vec2(20.0, -30.0));
+    }
+
     #[cfg(feature = "mint")]
     #[test]
     pub fn test_mint() {
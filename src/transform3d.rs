@@ -9,7 +9,7 @@
 
 #![allow(clippy::just_underscores_and_digits)]
 
-use super::{Angle, UnknownUnit};
+use super::{Angle, DisplayWithUnits, Unit, UnknownUnit};
 use crate::approxeq::ApproxEq;
 use crate::box2d::Box2D;
 use crate::box3d::Box3D;
@@ -32,7 +32,8 @@ use core::ops::{Add, Div, Mul, Neg, Sub};
 use bytemuck::{Pod, Zeroable};
 #[cfg(feature = "mint")]
 use mint;
-use num_traits::NumCast;
+use num_traits::real::Real;
+use num_traits::{Float, NumCast};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -190,6 +191,10 @@ where
     }
 }
 
+approx_impls!(Transform3D<Src, Dst> {
+    m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34, m41, m42, m43, m44
+});
+
 impl<T, Src, Dst> Transform3D<T, Src, Dst> {
     /// Create a transform specifying all of it's component as a 4 by 4 matrix.
     ///
@@ -275,6 +280,39 @@ impl<T, Src, Dst> Transform3D<T, Src, Dst> {
             && self.m33 == _1
             && self.m44 == _1
     }
+
+    /// Returns an object that displays this transform followed by the names of the
+    /// source and destination units, unless both are empty.
+    ///
+    /// ```rust
+    /// use euclid::{Transform3D, Unit};
+    ///
+    /// struct WorldSpace;
+    /// impl Unit for WorldSpace {
+    ///     const NAME: &'static str = "world";
+    /// }
+    ///
+    /// struct ScreenSpace;
+    /// impl Unit for ScreenSpace {
+    ///     const NAME: &'static str = "screen";
+    /// }
+    ///
+    /// let t: Transform3D<f32, WorldSpace, ScreenSpace> = Transform3D::translation(5.0, 6.0, 7.0);
+    ///
+    /// assert_eq!(t.to_string(), "[1, 0, 0, 0; 0, 1, 0, 0; 0, 0, 1, 0; 5, 6, 7, 1]");
+    /// assert_eq!(
+    ///     t.display_with_units().to_string(),
+    ///     "[1, 0, 0, 0; 0, 1, 0, 0; 0, 0, 1, 0; 5, 6, 7, 1] (world -> screen)"
+    /// );
+    /// ```
+    #[inline]
+    pub fn display_with_units(&self) -> DisplayWithUnits<'_, Self>
+    where
+        Src: Unit,
+        Dst: Unit,
+    {
+        DisplayWithUnits(self)
+    }
 }
 
 impl<T: Copy, Src, Dst> Transform3D<T, Src, Dst> {
@@ -342,6 +380,46 @@ impl<T: Copy, Src, Dst> Transform3D<T, Src, Dst> {
         ]
     }
 
+    /// Returns the component at the given row and column.
+    ///
+    /// Rows and columns are zero-based and follow the same layout as
+    /// `Transform3D::to_arrays`, so `get(3, 0)` returns `m41`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` or `col` is greater than 3.
+    #[inline]
+    pub fn get(&self, row: usize, col: usize) -> T {
+        debug_assert!(
+            row < 4 && col < 4,
+            "index out of bounds: ({}, {})",
+            row,
+            col
+        );
+        self.to_arrays()[row][col]
+    }
+
+    /// Sets the component at the given row and column.
+    ///
+    /// Rows and columns are zero-based and follow the same layout as
+    /// `Transform3D::to_arrays`, so `set(3, 0, v)` sets `m41`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` or `col` is greater than 3.
+    #[inline]
+    pub fn set(&mut self, row: usize, col: usize, value: T) {
+        debug_assert!(
+            row < 4 && col < 4,
+            "index out of bounds: ({}, {})",
+            row,
+            col
+        );
+        let mut arrays = self.to_arrays();
+        arrays[row][col] = value;
+        *self = Self::from_arrays(arrays);
+    }
+
     /// Create a transform providing its components via an array
     /// of 16 elements instead of as individual parameters.
     ///
@@ -400,6 +478,18 @@ impl<T: Copy, Src, Dst> Transform3D<T, Src, Dst> {
         )
     }
 
+    /// Cast the unit.
+    #[inline]
+    #[rustfmt::skip]
+    pub fn cast_unit<Src2, Dst2>(&self) -> Transform3D<T, Src2, Dst2> {
+        Transform3D::new(
+            self.m11, self.m12, self.m13, self.m14,
+            self.m21, self.m22, self.m23, self.m24,
+            self.m31, self.m32, self.m33, self.m34,
+            self.m41, self.m42, self.m43, self.m44,
+        )
+    }
+
     /// Returns the same transform with a different source unit.
     #[inline]
     #[rustfmt::skip]
@@ -433,6 +523,57 @@ impl<T: Copy, Src, Dst> Transform3D<T, Src, Dst> {
     pub fn to_2d(&self) -> Transform2D<T, Src, Dst> {
         Transform2D::new(self.m11, self.m12, self.m21, self.m22, self.m41, self.m42)
     }
+
+    /// Returns the terms of this transform that [`new_2d`] takes, in the same
+    /// order: `(m11, m12, m21, m22, m41, m42)`.
+    ///
+    /// The result is only meaningful if [`is_2d`] returns `true`, in which case
+    /// passing the components back to [`new_2d`] yields the same transform.
+    ///
+    /// [`new_2d`]: Self::new_2d
+    /// [`is_2d`]: Self::is_2d
+    #[inline]
+    pub fn to_2d_components(&self) -> (T, T, T, T, T, T) {
+        (self.m11, self.m12, self.m21, self.m22, self.m41, self.m42)
+    }
+
+    /// Create a 2D transform picking the relevant terms from this transform,
+    /// or `None` if this transform can't be represented with a `Transform2D`.
+    ///
+    /// See [`is_2d`].
+    ///
+    /// [`is_2d`]: Self::is_2d
+    pub fn try_to_2d(&self) -> Option<Transform2D<T, Src, Dst>>
+    where
+        T: Zero + One + PartialEq,
+    {
+        if !self.is_2d() {
+            return None;
+        }
+
+        Some(self.to_2d())
+    }
+}
+
+impl<T: Zero, Src, Dst> Transform3D<T, Src, Dst> {
+    /// Creates a matrix with all components set to zero:
+    ///
+    /// ```text
+    /// 0 0 0 0
+    /// 0 0 0 0
+    /// 0 0 0 0
+    /// 0 0 0 0
+    /// ```
+    #[inline]
+    #[rustfmt::skip]
+    pub fn zero() -> Self {
+        Self::new(
+            T::zero(), T::zero(), T::zero(), T::zero(),
+            T::zero(), T::zero(), T::zero(), T::zero(),
+            T::zero(), T::zero(), T::zero(), T::zero(),
+            T::zero(), T::zero(), T::zero(), T::zero(),
+        )
+    }
 }
 
 impl<T, Src, Dst> Transform3D<T, Src, Dst>
@@ -557,6 +698,22 @@ where
     }
 }
 
+impl<T, U> Transform3D<T, U, U>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Zero + One,
+{
+    /// Returns the combination of all transforms in the slice, in order.
+    ///
+    /// The first transform of the slice is applied first, so `compose_all(&[a, b, c])`
+    /// is equivalent to `a.then(&b).then(&c)`. An empty slice produces the identity.
+    #[must_use]
+    pub fn compose_all(transforms: &[Self]) -> Self {
+        transforms
+            .iter()
+            .fold(Self::identity(), |acc, transform| acc.then(transform))
+    }
+}
+
 /// Methods for creating and combining translation transformations
 impl<T, Src, Dst> Transform3D<T, Src, Dst>
 where
@@ -665,6 +822,60 @@ where
     pub fn pre_rotate(&self, x: T, y: T, z: T, theta: Angle<T>) -> Self {
         Transform3D::rotation(x, y, z, theta).then(self)
     }
+
+    /// Create a 3d rotation transform around the x axis.
+    ///
+    /// Unlike `Transform3D::rotation(1, 0, 0, theta)`, the terms are computed
+    /// directly from the sine and cosine of the angle.
+    #[rustfmt::skip]
+    pub fn rotation_x(theta: Angle<T>) -> Self {
+        let (_0, _1): (T, T) = (Zero::zero(), One::one());
+        let cos = theta.get().cos();
+        let sin = theta.get().sin();
+
+        Transform3D::new(
+            _1, _0,       _0,  _0,
+            _0, cos,      sin, _0,
+            _0, _0 - sin, cos, _0,
+            _0, _0,       _0,  _1,
+        )
+    }
+
+    /// Create a 3d rotation transform around the y axis.
+    ///
+    /// Unlike `Transform3D::rotation(0, 1, 0, theta)`, the terms are computed
+    /// directly from the sine and cosine of the angle.
+    #[rustfmt::skip]
+    pub fn rotation_y(theta: Angle<T>) -> Self {
+        let (_0, _1): (T, T) = (Zero::zero(), One::one());
+        let cos = theta.get().cos();
+        let sin = theta.get().sin();
+
+        Transform3D::new(
+            cos, _0, _0 - sin, _0,
+            _0,  _1, _0,       _0,
+            sin, _0, cos,      _0,
+            _0,  _0, _0,       _1,
+        )
+    }
+
+    /// Create a 3d rotation transform around the z axis.
+    ///
+    /// Unlike `Transform3D::rotation(0, 0, 1, theta)`, the terms are computed
+    /// directly from the sine and cosine of the angle.
+    #[rustfmt::skip]
+    pub fn rotation_z(theta: Angle<T>) -> Self {
+        let (_0, _1): (T, T) = (Zero::zero(), One::one());
+        let cos = theta.get().cos();
+        let sin = theta.get().sin();
+
+        Transform3D::new(
+            cos,      sin, _0, _0,
+            _0 - sin, cos, _0, _0,
+            _0,       _0,  _1, _0,
+            _0,       _0,  _0, _1,
+        )
+    }
 }
 
 /// Methods for creating and combining scale transformations
@@ -717,6 +928,32 @@ where
     {
         self.then(&Transform3D::scale(x, y, z))
     }
+
+    /// Create a 3d scale transform from the components of a vector.
+    ///
+    /// Equivalent to `Transform3D::scale(v.x, v.y, v.z)`.
+    #[inline]
+    pub fn scale_vector(v: Vector3D<T, Src>) -> Self {
+        Transform3D::scale(v.x, v.y, v.z)
+    }
+
+    /// Returns a transform with a scale, given as a vector, applied before self's transformation.
+    #[must_use]
+    pub fn pre_scale_vector(&self, v: Vector3D<T, Src>) -> Self
+    where
+        T: Copy + Add<Output = T> + Mul<Output = T>,
+    {
+        self.pre_scale(v.x, v.y, v.z)
+    }
+
+    /// Returns a transform with a scale, given as a vector, applied after self's transformation.
+    #[must_use]
+    pub fn then_scale_vector(&self, v: Vector3D<T, Dst>) -> Self
+    where
+        T: Copy + Add<Output = T> + Mul<Output = T>,
+    {
+        self.then_scale(v.x, v.y, v.z)
+    }
 }
 
 /// Methods for apply transformations to objects
@@ -825,6 +1062,42 @@ where
         ]))
     }
 
+    /// Computes [`outer_transformed_rect`] for each rectangle of `input`, writing
+    /// the results into `output`.
+    ///
+    /// If the transform has no perspective component, the rectangles are transformed
+    /// with [`Transform2D::outer_transformed_rects`], which avoids transforming the
+    /// four corners of each rectangle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` and `output` have different lengths.
+    ///
+    /// [`outer_transformed_rect`]: Self::outer_transformed_rect
+    pub fn outer_transformed_rects(
+        &self,
+        input: &[Rect<T, Src>],
+        output: &mut [Option<Rect<T, Dst>>],
+    ) where
+        T: Sub<Output = T> + Div<Output = T> + Zero + One + PartialOrd,
+    {
+        assert_eq!(input.len(), output.len());
+
+        let (_0, _1): (T, T) = (Zero::zero(), One::one());
+        if self.m14 == _0 && self.m24 == _0 && self.m44 == _1 {
+            let transform = self.to_2d();
+            let signs = transform.component_signs();
+            for (rect, out) in input.iter().zip(output.iter_mut()) {
+                *out = Some(transform.outer_transformed_rect_with_signs(signs, rect));
+            }
+            return;
+        }
+
+        for (rect, out) in input.iter().zip(output.iter_mut()) {
+            *out = self.outer_transformed_rect(rect);
+        }
+    }
+
     /// Returns a 2d box that encompasses the result of transforming the given box by this
     /// transform, if the transform makes sense for it, or `None` otherwise.
     pub fn outer_transformed_box2d(&self, b: &Box2D<T, Src>) -> Option<Box2D<T, Dst>>
@@ -889,6 +1162,42 @@ where
         )
     }
 
+    /// Create a perspective projection transform from a vertical field of view,
+    /// an aspect ratio (width / height) and the distances to the near and far
+    /// clip planes.
+    ///
+    /// This is the OpenGL convention, like [`ortho`]: the camera looks towards the
+    /// negative z axis and the visible frustum maps to clip space with z in `[-1, 1]`,
+    /// the near plane mapping to -1 and the far plane to 1 after the perspective
+    /// divide. Unlike the simpler [`perspective`], the resulting transform is
+    /// meant to be used as a projection matrix for rendering.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `near` is not positive or if `far` is not greater
+    /// than `near`.
+    ///
+    /// [`ortho`]: Self::ortho
+    /// [`perspective`]: Self::perspective
+    #[rustfmt::skip]
+    pub fn perspective_fov(fov_y: Angle<T>, aspect: T, near: T, far: T) -> Self
+    where
+        T: Trig,
+    {
+        let (_0, _1): (T, T) = (Zero::zero(), One::one());
+        debug_assert!(near > _0, "the near plane must be in front of the camera");
+        debug_assert!(far > near, "the far plane must be beyond the near plane");
+
+        let _2 = _1 + _1;
+        let f = _1 / (fov_y.radians / _2).tan();
+        Transform3D::new(
+            f / aspect, _0, _0                              ,  _0,
+            _0        , f , _0                              ,  _0,
+            _0        , _0, (far + near) / (near - far)     , -_1,
+            _0        , _0, _2 * far * near / (near - far)  ,  _0
+        )
+    }
+
     /// Check whether shapes on the XY plane with Z pointing towards the
     /// screen transformed by this matrix would be facing back.
     #[rustfmt::skip]
@@ -908,6 +1217,24 @@ where
         self.determinant() != Zero::zero()
     }
 
+    /// Returns whether the determinant of this transform is within `T`'s default
+    /// epsilon value of zero.
+    ///
+    /// Unlike [`is_invertible`], this also catches transforms with a tiny but non-zero
+    /// determinant. The epsilon is absolute and does not take the scale of the
+    /// transform into account: a small uniform scale is considered singular even
+    /// though it is well-conditioned, while an ill-conditioned transform with a large
+    /// determinant isn't.
+    ///
+    /// [`is_invertible`]: Self::is_invertible
+    #[inline]
+    pub fn is_singular(&self) -> bool
+    where
+        T: ApproxEq<T>,
+    {
+        self.determinant().approx_eq(&T::zero())
+    }
+
     /// Returns the inverse transform if possible.
     pub fn inverse(&self) -> Option<Transform3D<T, Dst, Src>> {
         let det = self.determinant();
@@ -989,6 +1316,19 @@ where
         Some(m.mul_s(_1 / det))
     }
 
+    /// Returns this transform expressed relative to `parent`, that is the
+    /// transform from `Src` to the local space of `parent`.
+    ///
+    /// If `self` and `parent` map to the same world space, this is equivalent
+    /// to `self.then(&parent.inverse()?)`. Returns `None` if `parent` is not
+    /// invertible.
+    pub fn relative_to<A>(
+        &self,
+        parent: &Transform3D<T, A, Dst>,
+    ) -> Option<Transform3D<T, Src, A>> {
+        Some(self.then(&parent.inverse()?))
+    }
+
     /// Compute the determinant of the transform.
     #[rustfmt::skip]
     pub fn determinant(&self) -> T {
@@ -1077,6 +1417,90 @@ where
     }
 }
 
+/// Methods for creating view transforms
+impl<T: Real, Src, Dst> Transform3D<T, Src, Dst> {
+    /// Create a right-handed view transform, looking from `eye` towards `target`.
+    ///
+    /// In the destination space the camera is at the origin, `up` maps to the
+    /// positive y axis and the view direction maps to the negative z axis.
+    /// This is the OpenGL convention, which [`ortho`] also assumes.
+    ///
+    /// `up` must not be parallel to the view direction.
+    ///
+    /// [`ortho`]: Self::ortho
+    #[rustfmt::skip]
+    pub fn look_at_rh(eye: Point3D<T, Src>,
+                      target: Point3D<T, Src>,
+                      up: Vector3D<T, Src>) -> Self {
+        let f = (target - eye).normalize();
+        let s = f.cross(up).normalize();
+        let u = s.cross(f);
+        let eye = eye.to_vector();
+
+        let (_0, _1): (T, T) = (Zero::zero(), One::one());
+        Transform3D::new(
+            s.x,          u.x,          -f.x,        _0,
+            s.y,          u.y,          -f.y,        _0,
+            s.z,          u.z,          -f.z,        _0,
+            -s.dot(eye),  -u.dot(eye),  f.dot(eye),  _1,
+        )
+    }
+
+    /// Create a left-handed view transform, looking from `eye` towards `target`.
+    ///
+    /// In the destination space the camera is at the origin, `up` maps to the
+    /// positive y axis and the view direction maps to the positive z axis.
+    /// This is the Direct3D convention.
+    ///
+    /// `up` must not be parallel to the view direction.
+    #[rustfmt::skip]
+    pub fn look_at_lh(eye: Point3D<T, Src>,
+                      target: Point3D<T, Src>,
+                      up: Vector3D<T, Src>) -> Self {
+        let f = (target - eye).normalize();
+        let s = up.cross(f).normalize();
+        let u = f.cross(s);
+        let eye = eye.to_vector();
+
+        let (_0, _1): (T, T) = (Zero::zero(), One::one());
+        Transform3D::new(
+            s.x,          u.x,          f.x,          _0,
+            s.y,          u.y,          f.y,          _0,
+            s.z,          u.z,          f.z,          _0,
+            -s.dot(eye),  -u.dot(eye),  -f.dot(eye),  _1,
+        )
+    }
+}
+
+impl<T: Real, Src, Dst> Transform3D<T, Src, Dst> {
+    /// Returns a transform with the same translation and perspective terms, and the
+    /// upper 3x3 part orthonormalized.
+    ///
+    /// The transformed x, y and z axes are made unit length and perpendicular to each
+    /// other using the Gram-Schmidt process, starting with the x axis. This corrects the
+    /// drift that accumulates when composing many rotations with floating point values.
+    ///
+    /// The upper 3x3 part must be invertible.
+    #[must_use]
+    #[rustfmt::skip]
+    pub fn orthonormalize(&self) -> Self {
+        let x: Vector3D<T, UnknownUnit> = vec3(self.m11, self.m12, self.m13);
+        let y: Vector3D<T, UnknownUnit> = vec3(self.m21, self.m22, self.m23);
+        let z: Vector3D<T, UnknownUnit> = vec3(self.m31, self.m32, self.m33);
+
+        let x = x.normalize();
+        let y = (y - x * x.dot(y)).normalize();
+        let z = (z - x * x.dot(z) - y * y.dot(z)).normalize();
+
+        Transform3D::new(
+            x.x,      x.y,      x.z,      self.m14,
+            y.x,      y.y,      y.z,      self.m24,
+            z.x,      z.y,      z.z,      self.m34,
+            self.m41, self.m42, self.m43, self.m44,
+        )
+    }
+}
+
 impl<T: NumCast + Copy, Src, Dst> Transform3D<T, Src, Dst> {
     /// Cast from one numeric representation to another, preserving the units.
     #[inline]
@@ -1109,11 +1533,30 @@ impl<T: NumCast + Copy, Src, Dst> Transform3D<T, Src, Dst> {
     }
 }
 
+impl<Src, Dst> Transform3D<f32, Src, Dst> {
+    /// Same as [`then`], but computes the products and sums in `f64` before rounding
+    /// the result back to `f32`.
+    ///
+    /// This reduces the accumulation of rounding errors when composing many transforms.
+    ///
+    /// [`then`]: Self::then
+    #[must_use]
+    pub fn then_precise<NewDst>(
+        &self,
+        other: &Transform3D<f32, Dst, NewDst>,
+    ) -> Transform3D<f32, Src, NewDst> {
+        self.cast::<f64>().then(&other.cast::<f64>()).cast()
+    }
+}
+
 impl<T: ApproxEq<T>, Src, Dst> Transform3D<T, Src, Dst> {
     /// Returns `true` if this transform is approximately equal to the other one, using
     /// `T`'s default epsilon value.
     ///
     /// The same as [`ApproxEq::approx_eq`] but available without importing trait.
+    ///
+    /// Components that are NaN in both transforms are not considered equal, see
+    /// [`approx_eq_nan_equal`](Self::approx_eq_nan_equal).
     #[inline]
     pub fn approx_eq(&self, other: &Self) -> bool {
         <Self as ApproxEq<T>>::approx_eq(self, other)
@@ -1127,6 +1570,22 @@ impl<T: ApproxEq<T>, Src, Dst> Transform3D<T, Src, Dst> {
     pub fn approx_eq_eps(&self, other: &Self, eps: &T) -> bool {
         <Self as ApproxEq<T>>::approx_eq_eps(self, other, eps)
     }
+
+    /// Returns `true` if this transform is approximately equal to the other one, using
+    /// `T`'s default epsilon value, and treating components that are NaN in both
+    /// transforms as equal.
+    ///
+    /// This is useful when comparing the results of operations on singular transforms.
+    #[inline]
+    pub fn approx_eq_nan_equal(&self, other: &Self) -> bool
+    where
+        T: Float,
+    {
+        self.to_array()
+            .iter()
+            .zip(other.to_array().iter())
+            .all(|(a, b)| (a.is_nan() && b.is_nan()) || a.approx_eq(b))
+    }
 }
 
 impl<T: ApproxEq<T>, Src, Dst> ApproxEq<T> for Transform3D<T, Src, Dst> {
@@ -1171,6 +1630,49 @@ where
     }
 }
 
+/// Formats the matrix one row at a time.
+///
+/// Use [`display_with_units`] to also display the names of the source and
+/// destination units.
+///
+/// [`display_with_units`]: Transform3D::display_with_units
+impl<T: fmt::Display, Src, Dst> fmt::Display for Transform3D<T, Src, Dst> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[{}, {}, {}, {}; {}, {}, {}, {}; {}, {}, {}, {}; {}, {}, {}, {}]",
+            self.m11,
+            self.m12,
+            self.m13,
+            self.m14,
+            self.m21,
+            self.m22,
+            self.m23,
+            self.m24,
+            self.m31,
+            self.m32,
+            self.m33,
+            self.m34,
+            self.m41,
+            self.m42,
+            self.m43,
+            self.m44,
+        )
+    }
+}
+
+impl<T: fmt::Display, Src: Unit, Dst: Unit> fmt::Display
+    for DisplayWithUnits<'_, Transform3D<T, Src, Dst>>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.0, f)?;
+        if !Src::NAME.is_empty() || !Dst::NAME.is_empty() {
+            write!(f, " ({} -> {})", Src::NAME, Dst::NAME)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(feature = "mint")]
 impl<T, Src, Dst> From<mint::RowMatrix4<T>> for Transform3D<T, Src, Dst> {
     #[rustfmt::skip]
@@ -1297,6 +1799,16 @@ mod tests {
         assert_eq!(m.then(&s), m.then_scale(2.0, 3.0, 4.0));
     }
 
+    #[test]
+    pub fn test_scale_vector() {
+        let v = vec3(2.0, 3.0, 4.0);
+        assert_eq!(Mf32::scale_vector(v), Mf32::scale(2.0, 3.0, 4.0));
+
+        let m = Mf32::translation(6.0, 7.0, 8.0);
+        assert_eq!(m.pre_scale_vector(v), m.pre_scale(2.0, 3.0, 4.0));
+        assert_eq!(m.then_scale_vector(v), m.then_scale(2.0, 3.0, 4.0));
+    }
+
     #[test]
     #[rustfmt::skip]
     pub fn test_ortho() {
@@ -1312,6 +1824,50 @@ mod tests {
         assert!(result.approx_eq(&expected));
     }
 
+    #[test]
+    pub fn test_perspective_fov() {
+        let (near, far) = (0.5f32, 100.0f32);
+        let m = Mf32::perspective_fov(rad(FRAC_PI_2), 2.0, near, far);
+
+        // With a 90° field of view, y = -z lies on the top plane of the frustum.
+        let p = m.transform_point3d(point3(0.0, near, -near)).unwrap();
+        assert!(p.approx_eq(&point3(0.0, 1.0, -1.0)));
+        let p = m.transform_point3d(point3(2.0 * far, 0.0, -far)).unwrap();
+        assert!(p.approx_eq(&point3(1.0, 0.0, 1.0)));
+        let p = m.transform_point3d(point3(0.0, 0.0, -near)).unwrap();
+        assert!(p.z.approx_eq(&-1.0));
+    }
+
+    #[test]
+    pub fn test_display() {
+        assert_eq!(
+            Mf32::translation(1.0, 2.5, 3.0).to_string(),
+            "[1, 0, 0, 0; 0, 1, 0, 0; 0, 0, 1, 0; 1, 2.5, 3, 1]"
+        );
+
+        // Units don't need to implement `Unit` to be displayed.
+        struct Mm;
+        let t: Transform3D<f32, Mm, Mm> = Transform3D::identity();
+        assert_eq!(
+            t.to_string(),
+            "[1, 0, 0, 0; 0, 1, 0, 0; 0, 0, 1, 0; 0, 0, 0, 1]"
+        );
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "the far plane must be beyond the near plane")]
+    pub fn test_perspective_fov_bad_far_plane() {
+        Mf32::perspective_fov(rad(FRAC_PI_2), 1.0, 10.0, 1.0);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "the near plane must be in front of the camera")]
+    pub fn test_perspective_fov_bad_near_plane() {
+        Mf32::perspective_fov(rad(FRAC_PI_2), 1.0, 0.0, 1.0);
+    }
+
     #[test]
     pub fn test_is_2d() {
         assert!(Mf32::identity().is_2d());
@@ -1319,6 +1875,165 @@ mod tests {
         assert!(!Mf32::rotation(0.0, 1.0, 0.0, rad(0.7854)).is_2d());
     }
 
+    #[test]
+    pub fn test_orthonormalize() {
+        let r = Mf32::rotation(0.0, 0.0, 1.0, rad(0.5))
+            .then(&Mf32::rotation(1.0, 0.0, 0.0, rad(0.3)))
+            .then_translate(vec3(1.0, 2.0, 3.0));
+        let mut perturbed = r;
+        perturbed.m11 += 0.01;
+        perturbed.m12 -= 0.02;
+        perturbed.m23 += 0.015;
+        perturbed.m32 -= 0.01;
+        perturbed.m33 += 0.02;
+
+        let m = perturbed.orthonormalize();
+
+        let x = vec3::<f32, ()>(m.m11, m.m12, m.m13);
+        let y = vec3::<f32, ()>(m.m21, m.m22, m.m23);
+        let z = vec3::<f32, ()>(m.m31, m.m32, m.m33);
+        assert!(x.length().approx_eq(&1.0));
+        assert!(y.length().approx_eq(&1.0));
+        assert!(z.length().approx_eq(&1.0));
+        assert!(x.dot(y).approx_eq(&0.0));
+        assert!(x.dot(z).approx_eq(&0.0));
+        assert!(y.dot(z).approx_eq(&0.0));
+        assert!(m.determinant().approx_eq(&1.0));
+        assert!(m.approx_eq_eps(&r, &0.05));
+        assert_eq!((m.m41, m.m42, m.m43, m.m44), (1.0, 2.0, 3.0, 1.0));
+
+        // An orthonormal transform is left unchanged.
+        assert!(r.orthonormalize().approx_eq(&r));
+    }
+
+    #[test]
+    pub fn test_look_at() {
+        let eye = point3(1.0, 2.0, 5.0);
+        let target = point3(1.0, 2.0, 0.0);
+        let up = vec3(0.0, 1.0, 0.0);
+
+        let rh = Mf32::look_at_rh(eye, target, up);
+        let lh = Mf32::look_at_lh(eye, target, up);
+
+        // The eye maps to the origin in both conventions.
+        assert!(rh
+            .transform_point3d(eye)
+            .unwrap()
+            .approx_eq(&point3(0.0, 0.0, 0.0)));
+        assert!(lh
+            .transform_point3d(eye)
+            .unwrap()
+            .approx_eq(&point3(0.0, 0.0, 0.0)));
+
+        // The forward axis is -z for right-handed and +z for left-handed.
+        assert!(rh
+            .transform_point3d(target)
+            .unwrap()
+            .approx_eq(&point3(0.0, 0.0, -5.0)));
+        assert!(lh
+            .transform_point3d(target)
+            .unwrap()
+            .approx_eq(&point3(0.0, 0.0, 5.0)));
+
+        // Up is +y in both conventions.
+        assert!(rh.transform_vector3d(up).approx_eq(&vec3(0.0, 1.0, 0.0)));
+        assert!(lh.transform_vector3d(up).approx_eq(&vec3(0.0, 1.0, 0.0)));
+
+        // Right is +x for right-handed, and -x for left-handed when looking down -z.
+        let right = vec3(1.0, 0.0, 0.0);
+        assert!(rh.transform_vector3d(right).approx_eq(&vec3(1.0, 0.0, 0.0)));
+        assert!(lh
+            .transform_vector3d(right)
+            .approx_eq(&vec3(-1.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    pub fn test_get_set() {
+        let mut m = Mf32::identity();
+        assert_eq!(m.get(0, 0), 1.0);
+        assert_eq!(m.get(0, 1), 0.0);
+
+        m.set(0, 0, 2.0);
+        m.set(3, 0, 5.0);
+        assert_eq!(m.get(0, 0), 2.0);
+        assert_eq!(m.m11, 2.0);
+        assert_eq!(m.m41, 5.0);
+        assert_eq!(
+            m,
+            Mf32::scale(2.0, 1.0, 1.0).then_translate(vec3(5.0, 0.0, 0.0))
+        );
+    }
+
+    #[test]
+    pub fn test_to_2d_components() {
+        let m = Mf32::new_2d(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+        assert_eq!(m.to_2d_components(), (1.0, 2.0, 3.0, 4.0, 5.0, 6.0));
+
+        let m = Mf32::rotation(0.0, 0.0, 1.0, rad(FRAC_PI_2)).then_translate(vec3(7.0, 8.0, 0.0));
+        assert!(m.is_2d());
+        let (m11, m12, m21, m22, m41, m42) = m.to_2d_components();
+        assert_eq!(Mf32::new_2d(m11, m12, m21, m22, m41, m42), m);
+    }
+
+    #[test]
+    pub fn test_relative_to() {
+        let parent =
+            Mf32::rotation(0.0, 0.0, 1.0, rad(FRAC_PI_2)).then_translate(vec3(1.0, 2.0, 3.0));
+        assert!(parent
+            .relative_to(&parent)
+            .unwrap()
+            .approx_eq(&Mf32::identity()));
+
+        let local = Mf32::translation(5.0, 0.0, 0.0);
+        let child = local.then(&parent);
+        assert!(child.relative_to(&parent).unwrap().approx_eq(&local));
+
+        assert!(child.relative_to(&Mf32::scale(0.0, 1.0, 1.0)).is_none());
+    }
+
+    #[test]
+    pub fn test_rotation_axes() {
+        let theta = rad(FRAC_PI_2);
+
+        let rz = Mf32::rotation_z(theta);
+        assert!(rz
+            .transform_point3d(point3(1.0, 0.0, 0.0))
+            .unwrap()
+            .approx_eq(&point3(0.0, 1.0, 0.0)));
+        assert!(rz.approx_eq(&Mf32::rotation(0.0, 0.0, 1.0, theta)));
+
+        let rx = Mf32::rotation_x(theta);
+        assert!(rx
+            .transform_point3d(point3(0.0, 1.0, 0.0))
+            .unwrap()
+            .approx_eq(&point3(0.0, 0.0, 1.0)));
+        assert!(rx.approx_eq(&Mf32::rotation(1.0, 0.0, 0.0, theta)));
+
+        let ry = Mf32::rotation_y(theta);
+        assert!(ry
+            .transform_point3d(point3(0.0, 0.0, 1.0))
+            .unwrap()
+            .approx_eq(&point3(1.0, 0.0, 0.0)));
+        assert!(ry.approx_eq(&Mf32::rotation(0.0, 1.0, 0.0, theta)));
+
+        let theta = rad(0.3);
+        assert!(Mf32::rotation_z(theta)
+            .to_2d()
+            .approx_eq(&Transform2D::rotation(theta)));
+    }
+
+    #[test]
+    pub fn test_try_to_2d() {
+        assert!(Mf32::rotation(0.0, 1.0, 0.0, rad(FRAC_PI_2))
+            .try_to_2d()
+            .is_none());
+        assert!(Mf32::translation(1.0, 2.0, 3.0).try_to_2d().is_none());
+
+        let m = Mf32::rotation(0.0, 0.0, 1.0, rad(FRAC_PI_2)).then_translate(vec3(1.0, 2.0, 0.0));
+        let expected = Transform2D::rotation(rad(FRAC_PI_2)).then_translate(vec2(1.0, 2.0));
+        assert!(m.try_to_2d().unwrap().approx_eq(&expected));
+    }
+
     #[test]
     #[rustfmt::skip]
     pub fn test_new_2d() {
@@ -1415,6 +2130,146 @@ mod tests {
         ));
     }
 
+    #[test]
+    pub fn test_approx_eq_eps() {
+        let m1: Mf32 = Mf32::translation(1.0, 2.0, 3.0).then_rotate(0.0, 0.0, 1.0, rad(0.5));
+        let mut m2 = m1;
+        m2.m11 += 1e-4;
+
+        assert!(!m1.approx_eq(&m2));
+        assert!(m1.approx_eq_eps(&m2, &1e-3));
+        assert!(!m1.approx_eq_eps(&m2, &1e-5));
+    }
+
+    #[test]
+    pub fn test_is_singular() {
+        assert!(Mf32::zero().is_singular());
+        assert!(Mf32::scale(1.0, 0.0, 2.0).is_singular());
+        assert!(!Mf32::identity().is_singular());
+        assert!(!Mf32::rotation(0.0, 1.0, 0.0, rad(0.5)).is_singular());
+        // The epsilon is absolute, so small uniform scales are singular too.
+        assert!(Mf32::scale(0.001, 0.001, 0.001).is_singular());
+        assert!(Mf32::scale(0.001, 0.001, 0.001).is_invertible());
+        assert_eq!(Mf32::zero().to_array(), [0.0; 16]);
+    }
+
+    #[test]
+    pub fn test_approx_eq_nan_equal() {
+        let mut m1 = Mf32::identity();
+        m1.m11 = f32::NAN;
+        let mut m2 = m1;
+        m2.m12 += 1e-7;
+
+        assert!(!m1.approx_eq(&m2));
+        assert!(m1.approx_eq_nan_equal(&m2));
+
+        m2.m11 = 1.0;
+        assert!(!m1.approx_eq_nan_equal(&m2));
+    }
+
+    #[test]
+    pub fn test_outer_transformed_rects() {
+        use crate::rect;
+
+        let input = [
+            rect(0.0, 0.0, 10.0, 10.0),
+            rect(-5.0, 3.0, 1.0, 20.0),
+            rect(2.0, 2.0, 0.0, 0.0),
+        ];
+        for m in [
+            Mf32::perspective(100.0).then_rotate(0.0, 0.0, 1.0, rad(0.5)),
+            Mf32::rotation(0.0, 0.0, 1.0, rad(2.5)).then_translate(vec3(1.0, 2.0, 3.0)),
+        ] {
+            let mut output = [None; 3];
+            m.outer_transformed_rects(&input, &mut output);
+            for (r, out) in input.iter().zip(output.iter()) {
+                assert_eq!(*out, m.outer_transformed_rect(r));
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_then_precise() {
+        // Measures how far the transform is from being orthonormal.
+        fn error(m: &Mf32) -> f64 {
+            let rows = m.cast::<f64>().to_arrays();
+            let mut max_error: f64 = 0.0;
+            for i in 0..3 {
+                for j in 0..3 {
+                    let dot: f64 = (0..3).map(|k| rows[i][k] * rows[j][k]).sum();
+                    let expected = if i == j { 1.0 } else { 0.0 };
+                    max_error = max_error.max((dot - expected).abs());
+                }
+            }
+            max_error
+        }
+
+        let axis: default::Vector3D<f32> = vec3(1.0, 2.0, 3.0).normalize();
+        let r = Mf32::rotation(axis.x, axis.y, axis.z, rad(0.001));
+        let mut naive = Mf32::identity();
+        let mut precise = Mf32::identity();
+        for _ in 0..10_000 {
+            naive = naive.then(&r);
+            precise = precise.then_precise(&r);
+        }
+
+        assert!(error(&precise) < error(&naive));
+
+        // Intermediate sums that cancel out are exact in f64.
+        let mut a = Mf32::identity();
+        a.m11 = 1.0e8;
+        a.m12 = 1.0;
+        a.m13 = -1.0e8;
+        let mut b = Mf32::identity();
+        b.m21 = 1.0;
+        b.m31 = 1.0;
+        assert_eq!(a.then(&b).m11, 0.0);
+        assert_eq!(a.then_precise(&b).m11, 1.0);
+    }
+
+    #[test]
+    pub fn test_untyped() {
+        enum A {}
+        enum B {}
+
+        let m: Transform3D<f32, A, B> = Transform3D::translation(1.0, 2.0, 3.0);
+        let u: Mf32 = m.to_untyped();
+        assert_eq!(u.to_array(), m.to_array());
+        assert_eq!(Transform3D::<f32, A, B>::from_untyped(&u), m);
+        assert_eq!(m.cast_unit::<B, A>().to_array(), m.to_array());
+    }
+
+    #[test]
+    pub fn test_then_units() {
+        enum Local {}
+        enum World {}
+        enum Screen {}
+
+        let to_world: Transform3D<f32, Local, World> = Transform3D::translation(1.0, 2.0, 3.0);
+        let to_screen: Transform3D<f32, World, Screen> = Transform3D::scale(2.0, 2.0, 2.0);
+
+        // `then` applies `self` first, and the units line up accordingly.
+        let local_to_screen: Transform3D<f32, Local, Screen> = to_world.then(&to_screen);
+        let p: Point3D<f32, Local> = point3(1.0, 1.0, 1.0);
+        assert_eq!(
+            local_to_screen.transform_point3d(p),
+            Some(point3(4.0, 6.0, 8.0))
+        );
+    }
+
+    #[test]
+    pub fn test_compose_all() {
+        let t = Mf32::translation(1.0, 2.0, 3.0);
+        let s = Mf32::scale(2.0, 3.0, 4.0);
+        let r = Mf32::rotation(0.0, 0.0, 1.0, rad(FRAC_PI_2));
+
+        assert_eq!(Mf32::compose_all(&[]), Mf32::identity());
+        assert_eq!(Mf32::compose_all(&[t]), t);
+        assert_eq!(Mf32::compose_all(&[t, s]), t.then(&s));
+        assert_eq!(Mf32::compose_all(&[s, t]), s.then(&t));
+        assert_eq!(Mf32::compose_all(&[t, s, r]), t.then(&s).then(&r));
+    }
+
     #[test]
     fn test_size_of() {
         use core::mem::size_of;
@@ -1519,6 +2374,34 @@ mod tests {
         assert_eq!(None, m.transform_point2d(p));
     }
 
+    #[test]
+    pub fn test_perspective_point3d() {
+        let m = Mf32::perspective(5.0);
+
+        // w = 1 - z / d, so points farther away (more negative z) shrink toward
+        // the origin.
+        let near = m.transform_point3d(point3(2.0, 2.0, 0.0)).unwrap();
+        let mid = m.transform_point3d(point3(2.0, 2.0, -5.0)).unwrap();
+        let far = m.transform_point3d(point3(2.0, 2.0, -15.0)).unwrap();
+        assert_eq!(near, point3(2.0, 2.0, 0.0));
+        assert_eq!(mid, point3(1.0, 1.0, -2.5));
+        assert_eq!(far, point3(0.5, 0.5, -3.75));
+
+        // Points behind the viewer (w <= 0) can't be projected.
+        assert_eq!(m.transform_point3d(point3(2.0, 2.0, 5.0)), None);
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    pub fn test_approx() {
+        let m1 = Mf32::rotation(0.0, 0.0, 1.0, rad(FRAC_PI_2));
+        let m2 = m1.then(&m1).then(&m1).then(&m1);
+
+        approx::assert_relative_eq!(m2, Mf32::identity(), epsilon = 1e-6);
+        approx::assert_abs_diff_eq!(m2, Mf32::identity(), epsilon = 1e-6);
+        approx::assert_relative_ne!(m1, Mf32::identity());
+    }
+
     #[cfg(feature = "mint")]
     #[test]
     pub fn test_mint() {
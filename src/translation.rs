@@ -204,6 +204,16 @@ impl<T: Copy, Src, Dst> Translation2D<T, Src, Dst> {
         }
     }
 
+    /// Cast the unit.
+    #[inline]
+    pub fn cast_unit<Src2, Dst2>(&self) -> Translation2D<T, Src2, Dst2> {
+        Translation2D {
+            x: self.x,
+            y: self.y,
+            _unit: PhantomData,
+        }
+    }
+
     /// Returns the matrix representation of this translation.
     #[inline]
     pub fn to_transform(&self) -> Transform2D<T, Src, Dst>
@@ -603,6 +613,17 @@ impl<T: Copy, Src, Dst> Translation3D<T, Src, Dst> {
         }
     }
 
+    /// Cast the unit.
+    #[inline]
+    pub fn cast_unit<Src2, Dst2>(&self) -> Translation3D<T, Src2, Dst2> {
+        Translation3D {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+            _unit: PhantomData,
+        }
+    }
+
     /// Returns the matrix representation of this translation.
     #[inline]
     pub fn to_transform(&self) -> Transform3D<T, Src, Dst>
@@ -856,6 +877,39 @@ mod _2d {
         assert!((tx + inv_tx).is_identity());
     }
 
+    #[test]
+    fn untyped() {
+        use crate::{default, Translation2D};
+
+        struct A;
+        struct B;
+
+        let tx: Translation2D<i32, A, B> = Translation2D::new(10, -10);
+        let u: default::Translation2D<i32> = tx.to_untyped();
+        assert_eq!(u, default::Translation2D::new(10, -10));
+        assert_eq!(Translation2D::<i32, A, B>::from_untyped(&u), tx);
+        assert_eq!(tx.cast_unit::<B, A>().to_tuple(), (10, -10));
+    }
+
+    #[test]
+    fn to_transform() {
+        use crate::{point2, vec2, Point2D, Translation2D, Vector2D};
+
+        struct A;
+        struct B;
+
+        let v: Vector2D<f32, A> = vec2(10.0, -10.0);
+        let tx: Translation2D<f32, A, B> = v.into();
+        let p: Point2D<f32, A> = point2(1.0, 2.0);
+        let expected = (p + v).cast_unit::<B>();
+        assert_eq!(tx.to_transform().transform_point(p), expected);
+        assert_eq!(tx.transform_point(p), expected);
+        assert_eq!(
+            Translation2D::<f32, A, B>::identity().transform_point(p),
+            p.cast_unit()
+        );
+    }
+
     /// Operation tests
     mod ops {
         use crate::default::Translation2D;
@@ -962,6 +1016,39 @@ mod _3d {
         assert!((tx + inv_tx).is_identity());
     }
 
+    #[test]
+    fn untyped() {
+        use crate::{default, Translation3D};
+
+        struct A;
+        struct B;
+
+        let tx: Translation3D<i32, A, B> = Translation3D::new(10, -10, 100);
+        let u: default::Translation3D<i32> = tx.to_untyped();
+        assert_eq!(u, default::Translation3D::new(10, -10, 100));
+        assert_eq!(Translation3D::<i32, A, B>::from_untyped(&u), tx);
+        assert_eq!(tx.cast_unit::<B, A>().to_tuple(), (10, -10, 100));
+    }
+
+    #[test]
+    fn to_transform() {
+        use crate::{point3, vec3, Point3D, Translation3D, Vector3D};
+
+        struct A;
+        struct B;
+
+        let v: Vector3D<f32, A> = vec3(10.0, -10.0, 100.0);
+        let tx: Translation3D<f32, A, B> = v.into();
+        let p: Point3D<f32, A> = point3(1.0, 2.0, 3.0);
+        let expected = (p + v).cast_unit::<B>();
+        assert_eq!(tx.to_transform().transform_point3d(p), Some(expected));
+        assert_eq!(tx.transform_point3d(&p), expected);
+        assert_eq!(
+            Translation3D::<f32, A, B>::identity().transform_point3d(&p),
+            p.cast_unit()
+        );
+    }
+
     /// Operation tests
     mod ops {
         use crate::default::Translation3D;
@@ -47,6 +47,7 @@ pub struct Vector2D<T, U> {
 }
 
 mint_vec!(Vector2D[x, y] = Vector2);
+approx_impls!(Vector2D<U> { x, y });
 
 impl<T: Copy, U> Copy for Vector2D<T, U> {}
 
@@ -197,6 +198,15 @@ impl<T, U> Vector2D<T, U> {
         vec2(length * angle.radians.cos(), length * angle.radians.sin())
     }
 
+    /// Constructor returning the unit vector pointing in the direction of `angle`,
+    /// that is `(cos(angle), sin(angle))`.
+    pub fn from_angle(angle: Angle<T>) -> Self
+    where
+        T: Trig + Copy,
+    {
+        vec2(angle.radians.cos(), angle.radians.sin())
+    }
+
     /// Constructor taking properly  Lengths instead of scalar values.
     #[inline]
     pub fn from_lengths(x: Length<T, U>, y: Length<T, U>) -> Self {
@@ -474,7 +484,8 @@ where
 
     /// Returns the signed angle between this vector and another vector.
     ///
-    /// The returned angle is between -PI and PI.
+    /// The returned angle is between -PI and PI. It is positive when `other` is
+    /// counter-clockwise from `self` with the y axis pointing up.
     pub fn angle_to(self, other: Self) -> Angle<T>
     where
         T: Sub<Output = T> + Trig,
@@ -533,6 +544,26 @@ impl<T: Real, U> Vector2D<T, U> {
         }
     }
 
+    /// Returns `true` if the length of this vector is lower than or equal to `eps`.
+    ///
+    /// Useful to check that a vector can be normalized without dividing by a value
+    /// close to zero.
+    #[inline]
+    pub fn is_approximately_zero(self, eps: T) -> bool {
+        self.square_length() <= eps * eps
+    }
+
+    /// Returns `true` if this vector and `other` point in the same or in opposite
+    /// directions, within a tolerance.
+    ///
+    /// `eps` bounds the sine of the angle between the two vectors, that is the norm
+    /// of their cross product divided by the product of their lengths.
+    /// A zero vector is considered parallel to any vector.
+    #[inline]
+    pub fn is_parallel_to(self, other: Self, eps: T) -> bool {
+        self.cross(other).abs() <= eps * self.length() * other.length()
+    }
+
     /// Return this vector scaled to fit the provided length.
     #[inline]
     pub fn with_length(self, length: T) -> Self {
@@ -567,6 +598,20 @@ impl<T: Real, U> Vector2D<T, U> {
         debug_assert!(min <= max);
         self.with_min_length(min).with_max_length(max)
     }
+
+    /// Normalized linear interpolation between this direction and another.
+    ///
+    /// Both vectors are expected to be unit vectors. The result is the normalized
+    /// linear interpolation of the two, which is cheaper than a spherical
+    /// interpolation and close to it for small angles.
+    ///
+    /// If the interpolated vector is zero, which happens halfway between two
+    /// opposite directions, `self` is returned.
+    #[inline]
+    #[must_use]
+    pub fn nlerp(self, other: Self, t: T) -> Self {
+        self.lerp(other, t).try_normalize().unwrap_or(self)
+    }
 }
 
 impl<T, U> Vector2D<T, U>
@@ -964,6 +1009,7 @@ pub struct Vector3D<T, U> {
 }
 
 mint_vec!(Vector3D[x, y, z] = Vector3);
+approx_impls!(Vector3D<U> { x, y, z });
 
 impl<T: Copy, U> Copy for Vector3D<T, U> {}
 
@@ -1115,6 +1161,19 @@ impl<T, U> Vector3D<T, U> {
         }
     }
 
+    /// Constructor returning the unit vector pointing in the direction given by
+    /// spherical angles.
+    ///
+    /// `azimuth` is measured in the xy plane from the x axis towards the y axis, and
+    /// `elevation` from the xy plane towards the z axis.
+    pub fn from_angles(azimuth: Angle<T>, elevation: Angle<T>) -> Self
+    where
+        T: Trig + Mul<Output = T> + Copy,
+    {
+        let (az, el) = (azimuth.radians, elevation.radians);
+        vec3(el.cos() * az.cos(), el.cos() * az.sin(), el.sin())
+    }
+
     /// Constructor taking properly  Lengths instead of scalar values.
     #[inline]
     pub fn from_lengths(x: Length<T, U>, y: Length<T, U>, z: Length<T, U>) -> Vector3D<T, U> {
@@ -1458,6 +1517,26 @@ impl<T: Real, U> Vector3D<T, U> {
         }
     }
 
+    /// Returns `true` if the length of this vector is lower than or equal to `eps`.
+    ///
+    /// Useful to check that a vector can be normalized without dividing by a value
+    /// close to zero.
+    #[inline]
+    pub fn is_approximately_zero(self, eps: T) -> bool {
+        self.square_length() <= eps * eps
+    }
+
+    /// Returns `true` if this vector and `other` point in the same or in opposite
+    /// directions, within a tolerance.
+    ///
+    /// `eps` bounds the sine of the angle between the two vectors, that is the norm
+    /// of their cross product divided by the product of their lengths.
+    /// A zero vector is considered parallel to any vector.
+    #[inline]
+    pub fn is_parallel_to(self, other: Self, eps: T) -> bool {
+        self.cross(other).length() <= eps * self.length() * other.length()
+    }
+
     /// Return this vector capped to a maximum length.
     #[inline]
     pub fn with_max_length(self, max_length: T) -> Self {
@@ -1486,6 +1565,30 @@ impl<T: Real, U> Vector3D<T, U> {
         debug_assert!(min <= max);
         self.with_min_length(min).with_max_length(max)
     }
+
+    /// Normalized linear interpolation between this direction and another.
+    ///
+    /// Both vectors are expected to be unit vectors. The result is the normalized
+    /// linear interpolation of the two, which is cheaper than a spherical
+    /// interpolation and close to it for small angles.
+    ///
+    /// If the interpolated vector is zero, which happens halfway between two
+    /// opposite directions, `self` is returned.
+    #[inline]
+    #[must_use]
+    pub fn nlerp(self, other: Self, t: T) -> Self {
+        self.lerp(other, t).try_normalize().unwrap_or(self)
+    }
+
+    /// Rotates this vector by `angle` around `axis`, which passes through the origin.
+    ///
+    /// The axis does not need to be normalized. Uses Rodrigues' rotation formula.
+    #[must_use]
+    pub fn rotate_around_axis(self, axis: Self, angle: Angle<T>) -> Self {
+        let k = axis.normalize();
+        let (sin, cos) = angle.sin_cos();
+        self * cos + k.cross(self) * sin + k * (k.dot(self) * (T::one() - cos))
+    }
 }
 
 impl<T, U> Vector3D<T, U>
@@ -2209,6 +2312,75 @@ mod vector2d {
         assert_eq!(p7.try_normalize().unwrap(), vec2(0.6, -0.8));
     }
 
+    #[test]
+    pub fn test_is_approximately_zero() {
+        assert!(Vec2::zero().is_approximately_zero(0.0));
+        let a: Vec2 = vec2(1e-4, -1e-4);
+        assert!(a.is_approximately_zero(1e-3));
+        assert!(!a.is_approximately_zero(1e-5));
+        assert!(!Vec2::new(1.0, 0.0).is_approximately_zero(1e-3));
+    }
+
+    #[test]
+    pub fn test_is_parallel_to() {
+        let a: Vec2 = vec2(1.0, 2.0);
+        assert!(a.is_parallel_to(a * 3.0, 1e-6));
+        assert!(a.is_parallel_to(-a, 1e-6));
+        assert!(a.is_parallel_to(vec2(1.0, 2.001), 1e-3));
+        assert!(!a.is_parallel_to(vec2(1.0, 2.001), 1e-5));
+        assert!(!a.is_parallel_to(vec2(-2.0, 1.0), 1e-3));
+    }
+
+    #[test]
+    pub fn test_point_difference() {
+        use crate::point2;
+
+        let a: default::Point2D<f32> = point2(1.0, 1.0);
+        let b: default::Point2D<f32> = point2(4.0, 5.0);
+        let c: default::Point2D<f32> = point2(-3.0, 4.0);
+
+        // The difference of two points is a vector, which has dot and cross.
+        let ab: Vec2 = b - a;
+        let ac: Vec2 = c - a;
+        assert_eq!(ab.dot(ac), 0.0);
+        assert_eq!(ab.cross(ac), 25.0);
+        assert_eq!((b - a).dot(b - a), ab.square_length());
+    }
+
+    #[test]
+    pub fn test_from_angle() {
+        use crate::approxeq::ApproxEq;
+        use crate::Angle;
+
+        let v = Vec2::from_angle(Angle::degrees(90.0));
+        assert!(v.approx_eq(&vec2(0.0, 1.0)));
+        let v = Vec2::from_angle(Angle::degrees(180.0));
+        assert!(v.approx_eq(&vec2(-1.0, 0.0)));
+    }
+
+    #[test]
+    pub fn test_component_mul_div() {
+        let a: Vec2 = vec2(2.0, 3.0);
+        let b: Vec2 = vec2(4.0, -6.0);
+        assert_eq!(a.component_mul(b), vec2(8.0, -18.0));
+        assert_eq!(b.component_div(a), vec2(2.0, -2.0));
+    }
+
+    #[test]
+    pub fn test_nlerp() {
+        use crate::approxeq::ApproxEq;
+        let a: Vec2 = vec2(1.0, 0.0);
+        let b: Vec2 = vec2(0.0, 1.0);
+        let n = a.nlerp(b, 0.5);
+        assert!((n.length() - 1.0).abs() < 1e-6);
+        assert!(n.approx_eq(&vec2(1.0, 1.0).normalize()));
+        assert_eq!(a.nlerp(b, 0.0), a);
+        assert_eq!(a.nlerp(b, 1.0), b);
+
+        // Opposite directions.
+        assert_eq!(a.nlerp(-a, 0.5), a);
+    }
+
     #[test]
     pub fn test_min() {
         let p1: Vec2 = vec2(1.0, 3.0);
@@ -2262,6 +2434,30 @@ mod vector2d {
             .approx_eq_eps(&(0.5 * FRAC_PI_2), &0.0005));
     }
 
+    #[test]
+    pub fn test_angle_to_between_axes() {
+        use crate::approxeq::ApproxEq;
+        use core::f32::consts::FRAC_PI_2;
+
+        // The angle is signed: rotating counter-clockwise (with y pointing up) is positive.
+        let x: Vec2 = vec2(1.0, 0.0);
+        let y: Vec2 = vec2(0.0, 1.0);
+        assert!(x.angle_to(y).get().approx_eq(&FRAC_PI_2));
+        assert!(y.angle_to(x).get().approx_eq(&-FRAC_PI_2));
+        assert!(y.angle_from_x_axis().approx_eq(&x.angle_to(y)));
+    }
+
+    #[test]
+    pub fn test_angle_to_signed() {
+        use crate::approxeq::ApproxEq;
+        use core::f32::consts::{FRAC_PI_2, PI};
+
+        // The result is in (-PI, PI].
+        let x: Vec2 = vec2(1.0, 0.0);
+        assert!(x.angle_to(vec2(0.0, -1.0)).get().approx_eq(&-FRAC_PI_2));
+        assert!(x.angle_to(vec2(-1.0, 0.0)).get().approx_eq(&PI));
+    }
+
     #[test]
     pub fn test_with_max_length() {
         use crate::approxeq::ApproxEq;
@@ -2345,6 +2541,21 @@ mod vector2d {
         assert_eq!(vecs.iter().sum::<Vector2DMm<_>>(), sum);
     }
 
+    #[test]
+    pub fn test_sum_owned_and_empty() {
+        let vecs = [
+            Vector2DMm::new(1.0, 2.0),
+            Vector2DMm::new(3.0, 4.0),
+            Vector2DMm::new(5.0, 6.0),
+        ];
+        let sum = Vector2DMm::new(9.0, 12.0);
+        assert_eq!(vecs.into_iter().sum::<Vector2DMm<_>>(), sum);
+        assert_eq!(
+            [].iter().sum::<Vector2DMm<f32>>(),
+            Vector2DMm::new(0.0, 0.0)
+        );
+    }
+
     #[test]
     pub fn test_add_assign() {
         let mut p1 = Vector2DMm::new(1.0, 2.0);
@@ -2410,6 +2621,18 @@ mod vector3d {
         assert_eq!(vecs.iter().sum::<Vec3>(), sum);
     }
 
+    #[test]
+    pub fn test_sum_owned_and_empty() {
+        let vecs = [
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(4.0, 5.0, 6.0),
+            Vec3::new(7.0, 8.0, 9.0),
+        ];
+        let sum = Vec3::new(12.0, 15.0, 18.0);
+        assert_eq!(vecs.into_iter().sum::<Vec3>(), sum);
+        assert_eq!([].iter().sum::<Vec3>(), Vec3::zero());
+    }
+
     #[test]
     pub fn test_dot() {
         let p1: Vec3 = vec3(7.0, 21.0, 32.0);
@@ -2425,6 +2648,15 @@ mod vector3d {
         assert_eq!(p3, vec3(-51.0, 105.0, -59.0));
     }
 
+    #[test]
+    pub fn test_cross_right_handed() {
+        let x = Vec3::new(1.0, 0.0, 0.0);
+        let y = Vec3::new(0.0, 1.0, 0.0);
+        let z = Vec3::new(0.0, 0.0, 1.0);
+        assert_eq!(x.cross(y), z);
+        assert_eq!(y.cross(x), -z);
+    }
+
     #[test]
     pub fn test_normalize() {
         use std::f32;
@@ -2459,6 +2691,93 @@ mod vector3d {
         assert_eq!(p7.try_normalize().unwrap(), vec3(0.6, -0.8, 0.0));
     }
 
+    #[test]
+    pub fn test_is_approximately_zero() {
+        assert!(Vec3::zero().is_approximately_zero(0.0));
+        let a: Vec3 = vec3(1e-4, -1e-4, 1e-4);
+        assert!(a.is_approximately_zero(1e-3));
+        assert!(!a.is_approximately_zero(1e-5));
+        assert!(!Vec3::new(0.0, 0.0, 1.0).is_approximately_zero(1e-3));
+    }
+
+    #[test]
+    pub fn test_is_parallel_to() {
+        let a: Vec3 = vec3(1.0, 2.0, 3.0);
+        assert!(a.is_parallel_to(a * 3.0, 1e-6));
+        assert!(a.is_parallel_to(-a, 1e-6));
+        assert!(a.is_parallel_to(vec3(1.0, 2.001, 3.0), 1e-3));
+        assert!(!a.is_parallel_to(vec3(1.0, 2.001, 3.0), 1e-5));
+        assert!(!a.is_parallel_to(vec3(3.0, 0.0, -1.0), 1e-3));
+    }
+
+    #[test]
+    pub fn test_point_difference() {
+        use crate::point3;
+
+        let a: default::Point3D<f32> = point3(1.0, 1.0, 1.0);
+        let b: default::Point3D<f32> = point3(2.0, 1.0, 1.0);
+        let c: default::Point3D<f32> = point3(1.0, 2.0, 1.0);
+
+        // The difference of two points is a vector, which has dot and cross.
+        let ab: Vec3 = b - a;
+        let ac: Vec3 = c - a;
+        assert_eq!(ab.dot(ac), 0.0);
+        assert_eq!(ab.cross(ac), vec3(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    pub fn test_from_angles() {
+        use crate::approxeq::ApproxEq;
+        use crate::Angle;
+
+        let v = Vec3::from_angles(Angle::degrees(90.0), Angle::zero());
+        assert!(v.approx_eq(&vec3(0.0, 1.0, 0.0)));
+        let v = Vec3::from_angles(Angle::degrees(45.0), Angle::degrees(90.0));
+        assert!(v.approx_eq(&vec3(0.0, 0.0, 1.0)));
+        let v = Vec3::from_angles(Angle::degrees(30.0), Angle::degrees(20.0));
+        assert!((v.length() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    pub fn test_component_mul_div() {
+        use crate::default::Vector3D;
+
+        let a: Vector3D<i32> = vec3(2, 3, 4);
+        let b: Vector3D<i32> = vec3(5, 6, 7);
+        assert_eq!(a.component_mul(b), vec3(10, 18, 28));
+        assert_eq!(vec3(10, 18, 28).component_div(b), a);
+    }
+
+    #[test]
+    pub fn test_nlerp() {
+        use crate::approxeq::ApproxEq;
+        let a: Vec3 = vec3(1.0, 0.0, 0.0);
+        let b: Vec3 = vec3(0.0, 0.0, 1.0);
+        let n = a.nlerp(b, 0.5);
+        assert!((n.length() - 1.0).abs() < 1e-6);
+        assert!(n.approx_eq(&vec3(1.0, 0.0, 1.0).normalize()));
+
+        // Opposite directions.
+        assert_eq!(a.nlerp(-a, 0.5), a);
+    }
+
+    #[test]
+    pub fn test_rotate_around_axis() {
+        use crate::approxeq::ApproxEq;
+        use crate::Angle;
+        use core::f32::consts::FRAC_PI_2;
+
+        let v: Vec3 = vec3(1.0, 0.0, 0.0);
+        let z: Vec3 = vec3(0.0, 0.0, 2.0);
+        let r = v.rotate_around_axis(z, Angle::radians(FRAC_PI_2));
+        assert!(r.approx_eq(&vec3(0.0, 1.0, 0.0)));
+
+        // Components along the axis are unaffected.
+        let v: Vec3 = vec3(1.0, 0.0, 3.0);
+        let r = v.rotate_around_axis(z, Angle::radians(FRAC_PI_2));
+        assert!(r.approx_eq(&vec3(0.0, 1.0, 3.0)));
+    }
+
     #[test]
     pub fn test_min() {
         let p1: Vec3 = vec3(1.0, 3.0, 5.0);